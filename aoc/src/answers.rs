@@ -0,0 +1,163 @@
+//! A local store of answers the website accepted, so a solver can be checked
+//! against them after a refactor without the inputs or answers being committed.
+
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::submit::History;
+
+/// Hashes a puzzle input (64-bit FNV-1a). It only has to tell inputs apart and
+/// stay the same between builds, which the std hashers don't promise.
+///
+/// * `input`: the contents of the input file
+pub fn input_hash(input: &str) -> u64 {
+    input.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// A confirmed answer for one part of a day, on one input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub day: u8,
+    pub part: u8,
+    pub input_hash: u64,
+    pub answer: String,
+}
+
+/// How a fresh answer compares with the stored one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    /// Same as the confirmed answer
+    Matches,
+    /// Different, with the confirmed answer
+    Differs(String),
+    /// Nothing has been confirmed for this input yet
+    Unknown,
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Check::Matches => write!(f, "ok"),
+            Check::Differs(expected) => write!(f, "expected {expected}"),
+            Check::Unknown => write!(f, "not confirmed yet"),
+        }
+    }
+}
+
+/// The answers file, one answer per line as
+/// `<day>\t<part>\t<input hash>\t<answer>`.
+#[derive(Debug)]
+pub struct Answers {
+    path: PathBuf,
+    answers: Vec<Answer>,
+}
+
+impl Answers {
+    /// Loads an answers file, which is empty if it doesn't exist yet. Lines
+    /// that can't be read are skipped.
+    pub fn load(path: impl Into<PathBuf>) -> io::Result<Self> {
+        let path = path.into();
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(err),
+        };
+
+        let answers = contents
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.splitn(4, '\t');
+                Some(Answer {
+                    day: fields.next()?.parse().ok()?,
+                    part: fields.next()?.parse().ok()?,
+                    input_hash: u64::from_str_radix(fields.next()?, 16).ok()?,
+                    answer: fields.next()?.to_string(),
+                })
+            })
+            .collect();
+
+        Ok(Answers { path, answers })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn answers(&self) -> &[Answer] {
+        &self.answers
+    }
+
+    /// Gets the confirmed answer for a part on an input, if there is one
+    pub fn get(&self, day: u8, part: u8, input_hash: u64) -> Option<&str> {
+        self.answers
+            .iter()
+            .find(|entry| entry.day == day && entry.part == part && entry.input_hash == input_hash)
+            .map(|entry| entry.answer.as_str())
+    }
+
+    /// Compares an answer with the confirmed one.
+    ///
+    /// * `answer`: the answer the solver gave
+    pub fn check(&self, day: u8, part: u8, input_hash: u64, answer: &str) -> Check {
+        match self.get(day, part, input_hash) {
+            Some(expected) if expected == answer => Check::Matches,
+            Some(expected) => Check::Differs(expected.to_string()),
+            None => Check::Unknown,
+        }
+    }
+
+    /// Records an answer as confirmed and saves the file, replacing whatever
+    /// was confirmed for the same part and input before.
+    pub fn confirm(&mut self, day: u8, part: u8, input_hash: u64, answer: &str) -> io::Result<()> {
+        self.answers.retain(|entry| {
+            !(entry.day == day && entry.part == part && entry.input_hash == input_hash)
+        });
+        self.answers.push(Answer {
+            day,
+            part,
+            input_hash,
+            answer: answer.to_string(),
+        });
+        self.answers.sort_by_key(|entry| (entry.day, entry.part));
+
+        let contents: String = self
+            .answers
+            .iter()
+            .map(|entry| {
+                format!(
+                    "{}\t{}\t{:016x}\t{}\n",
+                    entry.day, entry.part, entry.input_hash, entry.answer
+                )
+            })
+            .collect();
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&self.path, contents)
+    }
+
+    /// Confirms the answers the website accepted for a day, as recorded in its
+    /// submission history, and gets them by part.
+    ///
+    /// * `input_hash`: the hash of the input the history belongs to
+    /// * `history`: the day's submission history
+    pub fn confirm_accepted(
+        &mut self,
+        day: u8,
+        input_hash: u64,
+        history: &History,
+    ) -> io::Result<Vec<(u8, String)>> {
+        let mut confirmed = vec![];
+        for part in [1, 2] {
+            if let Some(answer) = history.correct(part) {
+                self.confirm(day, part, input_hash, answer)?;
+                confirmed.push((part, answer.to_string()));
+            }
+        }
+        Ok(confirmed)
+    }
+}
@@ -0,0 +1,61 @@
+//! Criterion benchmarks shared by every day. Each day's `benches/solution.rs`
+//! only has to pass its `Solution` to [`solution`].
+
+use std::{fs, path::Path};
+
+use criterion::{black_box, Criterion};
+
+use crate::Solution;
+
+/// Benchmarks parsing, part one and part two on the example input (`test.txt`,
+/// or `test2.txt` for part two when the day has a second example) and the full
+/// input (`input.txt`). Missing inputs are skipped.
+///
+/// Benchmarks are named `dayNN/<input>/<step>`, so a single day or step can be
+/// picked out with a filter, e.g. `cargo bench -- day05/full/part_two`.
+///
+/// * `c`: the criterion instance
+/// * `dir`: the day's crate directory, i.e. `env!("CARGO_MANIFEST_DIR")`
+pub fn solution<S: Solution>(c: &mut Criterion, dir: &str) {
+    let dir = Path::new(dir);
+    let second_example = match dir.join("test2.txt").exists() {
+        true => "test2.txt",
+        false => "test.txt",
+    };
+
+    let inputs = [
+        ("example", "test.txt", second_example),
+        ("full", "input.txt", "input.txt"),
+    ];
+    for (name, part_one_file, part_two_file) in inputs {
+        let (Ok(part_one), Ok(part_two)) = (
+            fs::read_to_string(dir.join(part_one_file)),
+            fs::read_to_string(dir.join(part_two_file)),
+        ) else {
+            eprintln!("day{:02}: skipping {name} input, file not found", S::DAY);
+            continue;
+        };
+        bench_input::<S>(c, name, &part_one, &part_two);
+    }
+}
+
+fn bench_input<S: Solution>(c: &mut Criterion, name: &str, part_one: &str, part_two: &str) {
+    let mut group = c.benchmark_group(format!("day{:02}/{name}", S::DAY));
+
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(part_one))));
+
+    match S::parse(part_one) {
+        Ok(input) => {
+            group.bench_function("part_one", |b| b.iter(|| S::part_one(black_box(&input))));
+        }
+        Err(err) => eprintln!("day{:02}: couldn't parse {name} input: {err}", S::DAY),
+    }
+    match S::parse(part_two) {
+        Ok(input) => {
+            group.bench_function("part_two", |b| b.iter(|| S::part_two(black_box(&input))));
+        }
+        Err(err) => eprintln!("day{:02}: couldn't parse {name} input: {err}", S::DAY),
+    }
+
+    group.finish();
+}
@@ -0,0 +1,591 @@
+//! Random puzzle inputs in the format of each day, for property tests. Every
+//! generator takes a seeded [`Rng`], so a failing input can be made again from
+//! its seed, and size parameters to keep the inputs small enough for slow
+//! reference solvers.
+//!
+//! The inputs keep the promises the real inputs make (e.g. every line of day 1
+//! has a digit, every day 8 ghost ends up on a cycle), since the solvers are
+//! allowed to rely on them.
+
+use std::ops::RangeInclusive;
+
+use crate::{
+    grid::Direction,
+    pipes::{self, PIPES},
+};
+
+/// A small seedable random number generator (SplitMix64). Not for anything
+/// but generating test inputs.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Gets a number in `range` (both ends included)
+    pub fn range(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let (start, end) = (*range.start(), *range.end());
+        match (end - start).checked_add(1) {
+            Some(len) => start + self.next_u64() % len,
+            None => self.next_u64(),
+        }
+    }
+
+    /// Gets an index into something `len` long
+    pub fn index(&mut self, len: usize) -> usize {
+        self.range(0..=len as u64 - 1) as usize
+    }
+
+    /// Returns true `percent` times in 100
+    pub fn chance(&mut self, percent: u64) -> bool {
+        self.range(1..=100) <= percent
+    }
+
+    pub fn pick<T: Copy>(&mut self, items: &[T]) -> T {
+        items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+
+    /// Gets `count` different numbers from `range`, in random order
+    pub fn distinct(&mut self, count: usize, range: RangeInclusive<u64>) -> Vec<u64> {
+        let mut numbers: Vec<u64> = range.collect();
+        self.shuffle(&mut numbers);
+        numbers.truncate(count);
+        numbers
+    }
+}
+
+const DIGIT_WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// Day 1: calibration lines of letters, digits and spelled out digits. Every
+/// line has at least one digit.
+///
+/// * `lines`: the number of lines
+/// * `max_pieces`: the most letters, digits and words on one line
+pub fn calibration(rng: &mut Rng, lines: usize, max_pieces: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..lines {
+        let mut pieces: Vec<String> = (0..rng.range(0..=max_pieces as u64 - 1))
+            .map(|_| match rng.range(1..=10) {
+                1..=6 => char::from(b'a' + rng.range(0..=25) as u8).to_string(),
+                7..=8 => rng.range(1..=9).to_string(),
+                _ => rng.pick(&DIGIT_WORDS).to_string(),
+            })
+            .collect();
+        let digit = rng.range(1..=9).to_string();
+        pieces.insert(rng.index(pieces.len() + 1), digit);
+
+        input += &pieces.concat();
+        input.push('\n');
+    }
+    input
+}
+
+/// Day 2: cube games, e.g. `Game 1: 3 blue, 4 red; 1 red, 2 green`.
+///
+/// * `games`: the number of games
+/// * `max_sets`: the most sets shown in one game
+/// * `max_cubes`: the most cubes of one colour in a set
+pub fn cube_games(rng: &mut Rng, games: usize, max_sets: usize, max_cubes: u64) -> String {
+    let mut input = String::new();
+    for id in 1..=games {
+        let sets: Vec<String> = (0..rng.range(1..=max_sets as u64))
+            .map(|_| {
+                let mut colors = ["red", "green", "blue"];
+                rng.shuffle(&mut colors);
+                let shown = rng.range(1..=3) as usize;
+                colors[..shown]
+                    .iter()
+                    .map(|color| format!("{} {color}", rng.range(1..=max_cubes)))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .collect();
+        input += &format!("Game {id}: {}\n", sets.join("; "));
+    }
+    input
+}
+
+const SYMBOLS: [char; 10] = ['*', '*', '*', '#', '+', '$', '/', '@', '=', '%'];
+
+/// Day 3: an engine schematic of numbers (up to 3 digits), symbols and `.`.
+/// Numbers on the same row are always apart.
+///
+/// * `width`: the number of columns
+/// * `height`: the number of rows
+pub fn schematic(rng: &mut Rng, width: usize, height: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..height {
+        let mut row = vec!['.'; width];
+        let mut col = rng.index(3);
+        while col < width {
+            match rng.range(1..=10) {
+                1..=3 => {
+                    let digits = (rng.range(1..=3) as usize).min(width - col);
+                    let number = rng.range(1..=999).to_string();
+                    for (cell, digit) in row[col..col + digits].iter_mut().zip(number.chars()) {
+                        *cell = digit;
+                    }
+                    // leave a gap so the next number is a different one
+                    col += digits + 1;
+                }
+                4..=5 => {
+                    row[col] = rng.pick(&SYMBOLS);
+                    col += 1;
+                }
+                _ => col += 1,
+            }
+        }
+        input.extend(row);
+        input.push('\n');
+    }
+    input
+}
+
+/// Day 4: scratchcards, e.g. `Card 1: 41 48 | 83 86 17`. A card never wins
+/// copies of cards past the end of the table.
+///
+/// * `cards`: the number of cards
+/// * `winning`: how many winning numbers each card has
+/// * `mine`: how many numbers I have on each card
+pub fn scratchcards(rng: &mut Rng, cards: usize, winning: usize, mine: usize) -> String {
+    let mut input = String::new();
+    for card in 1..=cards {
+        let most = winning.min(mine).min(cards - card) as u64;
+        let matches = rng.range(0..=most) as usize;
+
+        let numbers = rng.distinct(winning + mine - matches, 1..=99);
+        let winning_numbers = &numbers[..winning];
+        let mut my_numbers: Vec<u64> = winning_numbers[..matches]
+            .iter()
+            .chain(&numbers[winning..])
+            .copied()
+            .collect();
+        rng.shuffle(&mut my_numbers);
+
+        let format = |numbers: &[u64]| {
+            numbers
+                .iter()
+                .map(|number| format!("{number:>2}"))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        input += &format!(
+            "Card {card:>3}: {} | {}\n",
+            format(winning_numbers),
+            format(&my_numbers)
+        );
+    }
+    input
+}
+
+const ALMANAC_MAPS: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+/// Day 5: an almanac. Every map shuffles the blocks of `0..universe` around,
+/// so each step is one-to-one, and the seed ranges stay inside the universe.
+///
+/// * `seed_ranges`: the number of (start, length) pairs on the seeds line
+/// * `universe`: the numbers the maps move around
+/// * `blocks`: how many blocks each map cuts the universe into
+pub fn almanac(rng: &mut Rng, seed_ranges: usize, universe: u64, blocks: usize) -> String {
+    let seeds: Vec<String> = (0..seed_ranges)
+        .map(|_| {
+            let start = rng.range(0..=universe - 1);
+            let len = rng.range(1..=universe - start);
+            format!("{start} {len}")
+        })
+        .collect();
+    let mut input = format!("seeds: {}\n", seeds.join(" "));
+
+    for name in ALMANAC_MAPS {
+        let mut cuts = rng.distinct(blocks - 1, 1..=universe - 1);
+        cuts.extend([0, universe]);
+        cuts.sort_unstable();
+        let mut pieces: Vec<(u64, u64)> = cuts.windows(2).map(|w| (w[0], w[1] - w[0])).collect();
+        rng.shuffle(&mut pieces);
+
+        input += &format!("\n{name} map:\n");
+        let mut dest = 0;
+        for (source, len) in pieces {
+            // blocks that stay put may or may not be listed
+            if dest != source || rng.chance(50) {
+                input += &format!("{dest} {source} {len}\n");
+            }
+            dest += len;
+        }
+    }
+    input
+}
+
+/// Day 6: races, e.g. `Time: 7 15` over `Distance: 9 40`. Every record can be
+/// beaten.
+///
+/// * `races`: the number of races
+/// * `max_time`: the longest race
+pub fn races(rng: &mut Rng, races: usize, max_time: u64) -> String {
+    let (mut times, mut distances) = (String::new(), String::new());
+    for _ in 0..races {
+        let time = rng.range(2..=max_time);
+        let best = (time / 2) * (time - time / 2);
+        let distance = rng.range(0..=best - 1);
+        let width = distance.to_string().len().max(time.to_string().len()) + 2;
+        times += &format!("{time:>width$}");
+        distances += &format!("{distance:>width$}");
+    }
+    format!("Time:    {times}\nDistance:{distances}\n")
+}
+
+const CAMEL_CARDS: [char; 13] = [
+    '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A',
+];
+
+/// Day 7: camel cards hands with bids, e.g. `32T3K 765`. No hand is dealt
+/// twice. Hands are mostly drawn from a few labels so that pairs and better
+/// come up often.
+///
+/// * `hands`: the number of hands
+/// * `max_bid`: the biggest bid
+pub fn camel_hands(rng: &mut Rng, hands: usize, max_bid: u64) -> String {
+    let mut dealt: Vec<String> = vec![];
+    while dealt.len() < hands {
+        let labels: Vec<char> = match rng.chance(50) {
+            true => (0..rng.range(1..=4))
+                .map(|_| rng.pick(&CAMEL_CARDS))
+                .collect(),
+            false => CAMEL_CARDS.to_vec(),
+        };
+        let hand: String = (0..5).map(|_| rng.pick(&labels)).collect();
+        if !dealt.contains(&hand) {
+            dealt.push(hand);
+        }
+    }
+
+    dealt
+        .into_iter()
+        .map(|hand| format!("{hand} {}\n", rng.range(1..=max_bid)))
+        .collect()
+}
+
+/// Gets a node name from a number, ending in `last` (`None` for a letter that
+/// is neither A nor Z).
+fn node_name(number: usize, last: Option<char>) -> String {
+    const MIDDLE: &[u8] = b"BCDEFGHIJKLMNOPQRSTUVWXY";
+    let first = char::from(b'A' + (number / 26 % 26) as u8);
+    let second = char::from(b'A' + (number % 26) as u8);
+    let last = last.unwrap_or(char::from(MIDDLE[number / 676 % MIDDLE.len()]));
+    format!("{first}{second}{last}")
+}
+
+/// Day 8: L/R instructions and a network, e.g. `AAA = (BBB, CCC)`. Each ghost
+/// starts on an `..A` node (the first on `AAA`), follows the instructions onto
+/// a loop and reaches its `..Z` node (`ZZZ` for the first) every time it goes
+/// round. Each loop's length is a multiple of the number of instructions,
+/// like in the real inputs, so counting steps with the lcm works.
+///
+/// * `ghosts`: the number of ghosts (and `..A` nodes), at most 26
+/// * `instructions`: the number of L/R instructions
+/// * `max_laps`: the longest loop, in runs through the instructions
+pub fn network(rng: &mut Rng, ghosts: usize, instructions: usize, max_laps: u64) -> String {
+    let directions: Vec<bool> = (0..instructions).map(|_| rng.chance(50)).collect();
+    let mut nodes: Vec<(String, Option<String>, Option<String>)> = vec![];
+    let mut next_name = 0;
+
+    for ghost in 0..ghosts {
+        let (start, end) = match ghost {
+            0 => ("AAA".to_string(), "ZZZ".to_string()),
+            _ => (
+                node_name(ghost + 1, Some('A')),
+                node_name(ghost + 1, Some('Z')),
+            ),
+        };
+        let length = instructions * rng.range(1..=max_laps) as usize;
+
+        // the start leads onto the loop, whose last node is the end, which
+        // leads back to the loop's first node
+        let mut path = vec![start];
+        for _ in 1..length {
+            path.push(node_name(next_name, None));
+            next_name += 1;
+        }
+        path.push(end);
+
+        for (step, name) in path.iter().enumerate() {
+            let next = match step == length {
+                true => path[1].clone(),
+                false => path[step + 1].clone(),
+            };
+            let (left, right) = match directions[step % instructions] {
+                true => (Some(next), None),
+                false => (None, Some(next)),
+            };
+            nodes.push((name.clone(), left, right));
+        }
+    }
+
+    // the way not taken goes anywhere
+    let names: Vec<String> = nodes.iter().map(|(name, _, _)| name.clone()).collect();
+    let mut lines: Vec<String> = nodes
+        .into_iter()
+        .map(|(name, left, right)| {
+            let left = left.unwrap_or_else(|| names[rng.index(names.len())].clone());
+            let right = right.unwrap_or_else(|| names[rng.index(names.len())].clone());
+            format!("{name} = ({left}, {right})\n")
+        })
+        .collect();
+    rng.shuffle(&mut lines);
+
+    let directions: String = directions
+        .iter()
+        .map(|left| if *left { 'L' } else { 'R' })
+        .collect();
+    format!("{directions}\n\n{}", lines.concat())
+}
+
+/// Day 9: OASIS histories, e.g. `0 3 6 9 12 15`, each the values of a random
+/// polynomial at 0, 1, 2... Some have a degree too high for their length to
+/// ever reach a row of zeros.
+///
+/// * `histories`: the number of histories
+/// * `len`: how many values each history has
+/// * `max_degree`: the highest degree of a polynomial
+pub fn histories(rng: &mut Rng, histories: usize, len: usize, max_degree: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..histories {
+        // the polynomial as the first value of each row of differences, so
+        // every row is whole numbers
+        let degree = rng.index(max_degree + 1);
+        let mut rows: Vec<i64> = (0..=degree)
+            .map(|_| rng.range(0..=40) as i64 - 20)
+            .collect();
+
+        let mut values = vec![];
+        for _ in 0..len {
+            values.push(rows[0].to_string());
+            // step every row along by the row under it
+            for row in 0..degree {
+                rows[row] += rows[row + 1];
+            }
+        }
+        input += &values.join(" ");
+        input.push('\n');
+    }
+    input
+}
+
+/// Day 10: a pipe maze, e.g. `.F-7.`, with one loop through `S` and random
+/// pipes everywhere else. The loop is the outline of a random blob of tiles,
+/// so it twists around, and `S` connects to exactly two pipes like in the
+/// real inputs.
+///
+/// * `width`, `height`: the size of the maze, at least 2 by 2
+/// * `blob`: how many tiles to grow the blob to, it stops early when it can't
+///   grow any more without the outline touching itself
+pub fn pipe_maze(rng: &mut Rng, width: usize, height: usize, blob: usize) -> String {
+    // the blob is made of the squares between the maze's tiles, so its
+    // outline runs through the tiles
+    let (rows, cols) = (height - 1, width - 1);
+    let mut squares = vec![vec![false; cols]; rows];
+    squares[rng.index(rows)][rng.index(cols)] = true;
+
+    let mut size = 1;
+    for _ in 0..rows * cols * 20 {
+        if size == blob {
+            break;
+        }
+        let (row, col) = (rng.index(rows), rng.index(cols));
+        let touching = [(0, 1), (0, -1), (1, 0), (-1, 0)]
+            .into_iter()
+            .any(|offset| square(&squares, row, col, offset));
+        if squares[row][col] || !touching {
+            continue;
+        }
+        squares[row][col] = true;
+        match outline(&squares, width, height) {
+            Some(_) => size += 1,
+            // a hole, or two corners of the blob touching
+            None => squares[row][col] = false,
+        }
+    }
+
+    let mut tiles = outline(&squares, width, height).expect("the blob is kept in one piece");
+    let on_loop: Vec<(usize, usize)> = (0..height)
+        .flat_map(|row| (0..width).map(move |col| (row, col)))
+        .filter(|&(row, col)| tiles[row][col] != '.')
+        .collect();
+    for (row, line) in tiles.iter_mut().enumerate() {
+        for (col, tile) in line.iter_mut().enumerate() {
+            if !on_loop.contains(&(row, col)) && rng.chance(60) {
+                *tile = rng.pick(&PIPES).0;
+            }
+        }
+    }
+
+    // pipes off the loop mustn't connect to `S`, or its pipe would be unclear
+    let (row, col) = on_loop[rng.index(on_loop.len())];
+    tiles[row][col] = 'S';
+    for direction in Direction::ALL {
+        let (rows, cols) = direction.offset();
+        let (Some(next_row), Some(next_col)) =
+            (row.checked_add_signed(rows), col.checked_add_signed(cols))
+        else {
+            continue;
+        };
+        let Some(tile) = tiles
+            .get_mut(next_row)
+            .and_then(|line| line.get_mut(next_col))
+        else {
+            continue;
+        };
+        let connects =
+            pipes::connections(*tile).is_some_and(|ends| ends.contains(&direction.opposite()));
+        if connects && !on_loop.contains(&(next_row, next_col)) {
+            *tile = '.';
+        }
+    }
+
+    tiles
+        .into_iter()
+        .map(|line| line.into_iter().chain(['\n']).collect::<String>())
+        .collect()
+}
+
+/// Checks whether the square `offset` from (`row`, `col`) is in the blob.
+fn square(squares: &[Vec<bool>], row: usize, col: usize, offset: (isize, isize)) -> bool {
+    let (Some(row), Some(col)) = (
+        row.checked_add_signed(offset.0),
+        col.checked_add_signed(offset.1),
+    ) else {
+        return false;
+    };
+    squares
+        .get(row)
+        .and_then(|line| line.get(col))
+        .is_some_and(|&square| square)
+}
+
+/// Draws the outline of a blob of squares as pipes, `.` off it. `None` if the
+/// outline isn't one loop that never touches itself.
+fn outline(squares: &[Vec<bool>], width: usize, height: usize) -> Option<Vec<Vec<char>>> {
+    let mut tiles = vec![vec!['.'; width]; height];
+    let mut length = 0;
+    for (row, line) in tiles.iter_mut().enumerate() {
+        for (col, tile) in line.iter_mut().enumerate() {
+            // the tile is the corner of four squares, and the outline goes
+            // between two of them that are on different sides of it
+            let is_in = |offset| square(squares, row, col, offset);
+            let ends: Vec<Direction> = [
+                (Direction::North, (-1, -1), (-1, 0)),
+                (Direction::East, (-1, 0), (0, 0)),
+                (Direction::South, (0, -1), (0, 0)),
+                (Direction::West, (-1, -1), (0, -1)),
+            ]
+            .into_iter()
+            .filter(|&(_, one, two)| is_in(one) != is_in(two))
+            .map(|(direction, _, _)| direction)
+            .collect();
+
+            match ends[..] {
+                [] => {}
+                [one, two] => {
+                    *tile = pipes::pipe_between(one, two);
+                    length += 1;
+                }
+                _ => return None,
+            }
+        }
+    }
+
+    // follow the outline from its first tile: if it's one loop it comes back
+    // after visiting every tile on it
+    let start = (0..height)
+        .flat_map(|row| (0..width).map(move |col| (row, col)))
+        .find(|&(row, col)| tiles[row][col] != '.')?;
+    let direction = pipes::connections(tiles[start.0][start.1])?[0];
+    let path = pipes::walk_loop(start, direction, |(row, col)| {
+        tiles.get(row)?.get(col).copied()
+    })
+    .ok()?;
+    (path.len() == length).then_some(tiles)
+}
+
+/// Day 11: an image of space (`.`) and galaxies (`#`). Some rows and columns
+/// are kept empty, so there's always something to expand.
+///
+/// * `width`, `height`: the size of the image
+/// * `percent`: the chance of a tile outside the empty rows and columns being
+///   a galaxy
+pub fn galaxy_image(rng: &mut Rng, width: usize, height: usize, percent: u64) -> String {
+    let empty_rows: Vec<bool> = (0..height).map(|_| rng.chance(25)).collect();
+    let empty_cols: Vec<bool> = (0..width).map(|_| rng.chance(25)).collect();
+
+    let mut input = String::new();
+    for &empty_row in &empty_rows {
+        for &empty_col in &empty_cols {
+            match !empty_row && !empty_col && rng.chance(percent) {
+                true => input.push('#'),
+                false => input.push('.'),
+            }
+        }
+        input.push('\n');
+    }
+    input
+}
+
+/// Day 12: condition records, e.g. `???.### 1,1,3`. Each row is made from
+/// springs that are all known, then some are hidden behind `?`, so every row
+/// has at least one arrangement like in the real inputs.
+///
+/// * `rows`: the number of rows
+/// * `max_len`: the most springs in a row
+/// * `unknown`: the chance of a spring being hidden, in percent
+pub fn spring_records(rng: &mut Rng, rows: usize, max_len: usize, unknown: u64) -> String {
+    let mut input = String::new();
+    for _ in 0..rows {
+        let len = rng.range(1..=max_len as u64) as usize;
+        let mut springs: Vec<bool> = (0..len).map(|_| rng.chance(50)).collect();
+        // every row has a group
+        springs[rng.index(len)] = true;
+
+        let groups: Vec<String> = springs
+            .split(|&damaged| !damaged)
+            .filter(|group| !group.is_empty())
+            .map(|group| group.len().to_string())
+            .collect();
+        for damaged in springs {
+            match (rng.chance(unknown), damaged) {
+                (true, _) => input.push('?'),
+                (false, true) => input.push('#'),
+                (false, false) => input.push('.'),
+            }
+        }
+        input += &format!(" {}\n", groups.join(","));
+    }
+    input
+}
@@ -0,0 +1,147 @@
+//! Inputs that are a picture: a rectangle of characters looked at cell by
+//! cell, together with the cells around each one (day 3's schematic, day 10's
+//! pipes).
+
+use std::ops::RangeInclusive;
+
+use crate::ParseError;
+
+/// Where a cell is: (row, column), both from 0 at the top left.
+pub type Pos = (usize, usize);
+
+/// A step to one of a cell's four orthogonal neighbours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// Every direction, clockwise from north
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub fn opposite(self) -> Direction {
+        match self {
+            Direction::North => Direction::South,
+            Direction::East => Direction::West,
+            Direction::South => Direction::North,
+            Direction::West => Direction::East,
+        }
+    }
+
+    /// Gets how a step changes the row and the column.
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::North => (-1, 0),
+            Direction::East => (0, 1),
+            Direction::South => (1, 0),
+            Direction::West => (0, -1),
+        }
+    }
+}
+
+/// A rectangle of ASCII characters, one row per line of the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid {
+    rows: Vec<Vec<char>>,
+}
+
+impl Grid {
+    /// Reads a grid, one row per line. The grid has to be a rectangle of ASCII
+    /// characters, so a column is the same in every row.
+    pub fn parse(contents: &str) -> Result<Grid, ParseError> {
+        if let Some(c) = contents.chars().find(|c| !c.is_ascii()) {
+            return Err(format!("unexpected character `{c}` in the grid").into());
+        }
+        let width = contents.lines().next().map_or(0, str::len);
+        if let Some((line, _)) = contents
+            .lines()
+            .enumerate()
+            .find(|(_, line)| line.len() != width)
+        {
+            return Err(format!("line {}: every line should be {width} long", line + 1).into());
+        }
+
+        Ok(Grid {
+            rows: contents
+                .lines()
+                .map(|line| line.chars().collect())
+                .collect(),
+        })
+    }
+
+    pub fn rows(&self) -> &[Vec<char>] {
+        &self.rows
+    }
+
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn width(&self) -> usize {
+        self.rows.first().map_or(0, Vec::len)
+    }
+
+    /// Gets the character at `pos`, or `None` outside the grid.
+    pub fn get(&self, pos: Pos) -> Option<char> {
+        self.rows.get(pos.0)?.get(pos.1).copied()
+    }
+
+    /// Gets every cell and its character, in reading order.
+    pub fn cells(&self) -> impl Iterator<Item = (Pos, char)> + '_ {
+        self.rows.iter().enumerate().flat_map(|(row, line)| {
+            line.iter()
+                .enumerate()
+                .map(move |(col, &char)| ((row, col), char))
+        })
+    }
+
+    /// Finds the first cell (in reading order) with the character `target`.
+    pub fn find(&self, target: char) -> Option<Pos> {
+        self.cells()
+            .find(|&(_, char)| char == target)
+            .map(|(pos, _)| pos)
+    }
+
+    /// Gets the cell one step from `pos`, or `None` if that's outside the grid.
+    pub fn step(&self, pos: Pos, direction: Direction) -> Option<Pos> {
+        let (rows, cols) = direction.offset();
+        let next = (
+            pos.0.checked_add_signed(rows)?,
+            pos.1.checked_add_signed(cols)?,
+        );
+        self.get(next).map(|_| next)
+    }
+
+    /// Gets the orthogonal neighbours of `pos` that are in the grid, with the
+    /// direction of each.
+    pub fn neighbours(&self, pos: Pos) -> impl Iterator<Item = (Direction, Pos)> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| Some((direction, self.step(pos, direction)?)))
+    }
+
+    /// Gets the cells of the box around a run of cells on one row: the run and
+    /// every cell touching it, diagonals included, in reading order. Cells
+    /// outside the grid are left out.
+    ///
+    /// * `row`: the run's row
+    /// * `cols`: the run's first and last column
+    pub fn around(
+        &self,
+        row: usize,
+        cols: RangeInclusive<usize>,
+    ) -> impl Iterator<Item = Pos> + '_ {
+        let rows = row.saturating_sub(1)..=row + 1;
+        let cols = cols.start().saturating_sub(1)..=cols.end() + 1;
+        rows.flat_map(move |row| cols.clone().map(move |col| (row, col)))
+            .filter(|&pos| self.get(pos).is_some())
+    }
+}
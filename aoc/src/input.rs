@@ -0,0 +1,318 @@
+//! Puzzle inputs, cached on disk and fetched from the website the first time
+//! they're needed.
+
+use std::{
+    env, fmt, fs, io,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// The site inputs are fetched from by default.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// The environment variable checked for the session token first.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// The shortest time allowed between two requests, shared by every process
+/// using the same cache.
+pub const DEFAULT_RATE_LIMIT: Duration = Duration::from_secs(5);
+
+#[cfg(feature = "http")]
+const USER_AGENT: &str = "github.com/MikeWelsh801/AoC2023 input fetcher";
+
+#[derive(Debug)]
+pub enum InputError {
+    /// No session token in the environment or config file
+    NoSession,
+    /// The server answered with something other than 200 OK
+    Status {
+        status: u16,
+        body: String,
+    },
+    /// The request never got an answer
+    Transport(String),
+    Io(io::Error),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::NoSession => write!(
+                f,
+                "no session token, set {SESSION_VAR} or add `session = ...` to {}",
+                config_path().map_or("the config file".to_string(), |path| path
+                    .display()
+                    .to_string())
+            ),
+            InputError::Status { status, body } => {
+                write!(f, "server answered {status}: {}", body.trim())
+            }
+            InputError::Transport(err) => write!(f, "request failed: {err}"),
+            InputError::Io(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+impl From<io::Error> for InputError {
+    fn from(err: io::Error) -> Self {
+        InputError::Io(err)
+    }
+}
+
+/// Makes the HTTP requests for an `InputManager` (and answer submission), so
+/// tests (or a different HTTP library) can stand in for the real client.
+pub trait HttpBackend {
+    /// Sends a GET request with the session cookie and returns the body of a
+    /// 200 response.
+    ///
+    /// * `url`: the full URL to request
+    /// * `session`: the session token sent as the `session` cookie
+    fn get(&self, url: &str, session: &str) -> Result<String, InputError>;
+
+    /// Sends a form-encoded POST request with the session cookie and returns
+    /// the body of a 200 response.
+    ///
+    /// * `url`: the full URL to request
+    /// * `session`: the session token sent as the `session` cookie
+    /// * `form`: the fields to send
+    fn post_form(
+        &self,
+        url: &str,
+        session: &str,
+        form: &[(&str, &str)],
+    ) -> Result<String, InputError>;
+}
+
+/// The default backend, built on `ureq`.
+#[cfg(feature = "http")]
+pub struct UreqBackend {
+    agent: ureq::Agent,
+}
+
+#[cfg(feature = "http")]
+impl UreqBackend {
+    pub fn new() -> Self {
+        UreqBackend {
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .user_agent(USER_AGENT)
+                .build(),
+        }
+    }
+
+    fn read(response: Result<ureq::Response, ureq::Error>) -> Result<String, InputError> {
+        match response {
+            Ok(response) => Ok(response.into_string()?),
+            Err(ureq::Error::Status(status, response)) => Err(InputError::Status {
+                status,
+                body: response.into_string().unwrap_or_default(),
+            }),
+            Err(err) => Err(InputError::Transport(err.to_string())),
+        }
+    }
+}
+
+#[cfg(feature = "http")]
+impl Default for UreqBackend {
+    fn default() -> Self {
+        UreqBackend::new()
+    }
+}
+
+#[cfg(feature = "http")]
+impl HttpBackend for UreqBackend {
+    fn get(&self, url: &str, session: &str) -> Result<String, InputError> {
+        let cookie = format!("session={session}");
+        Self::read(self.agent.get(url).set("Cookie", &cookie).call())
+    }
+
+    fn post_form(
+        &self,
+        url: &str,
+        session: &str,
+        form: &[(&str, &str)],
+    ) -> Result<String, InputError> {
+        let cookie = format!("session={session}");
+        Self::read(self.agent.post(url).set("Cookie", &cookie).send_form(form))
+    }
+}
+
+/// Finds puzzle inputs in a cache directory, fetching the ones that are
+/// missing. An input is only ever fetched once.
+pub struct InputManager<B: HttpBackend> {
+    cache_dir: PathBuf,
+    base_url: String,
+    session: Option<String>,
+    rate_limit: Duration,
+    backend: B,
+}
+
+impl<B: HttpBackend> InputManager<B> {
+    /// Constructs a manager for the real site. Set a session token (e.g. from
+    /// `find_session`) before fetching anything.
+    ///
+    /// * `cache_dir`: where inputs are stored, as `<year>/day<NN>.txt`
+    /// * `backend`: makes the HTTP requests
+    pub fn new(cache_dir: impl Into<PathBuf>, backend: B) -> Self {
+        InputManager {
+            cache_dir: cache_dir.into(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            session: None,
+            rate_limit: DEFAULT_RATE_LIMIT,
+            backend,
+        }
+    }
+
+    /// Sets the site to fetch from (e.g. a local mock server)
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Sets the session token, replacing the one that was found
+    pub fn with_session(mut self, session: &str) -> Self {
+        self.session = Some(session.trim().to_string());
+        self
+    }
+
+    /// Sets the shortest time allowed between two requests
+    pub fn with_rate_limit(mut self, rate_limit: Duration) -> Self {
+        self.rate_limit = rate_limit;
+        self
+    }
+
+    pub fn cache_dir(&self) -> &Path {
+        &self.cache_dir
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    pub fn backend(&self) -> &B {
+        &self.backend
+    }
+
+    /// Gets the session token, or an error saying where to put one
+    pub fn session(&self) -> Result<&str, InputError> {
+        self.session.as_deref().ok_or(InputError::NoSession)
+    }
+
+    /// Gets where a day's input is (or would be) cached
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        cache_path(&self.cache_dir, year, day)
+    }
+
+    /// Gets a day's input if it's already cached
+    pub fn cached(&self, year: u16, day: u8) -> Option<String> {
+        fs::read_to_string(self.path(year, day)).ok()
+    }
+
+    /// Gets a day's input, fetching and caching it if it isn't cached yet
+    pub fn get(&self, year: u16, day: u8) -> Result<String, InputError> {
+        if let Some(input) = self.cached(year, day) {
+            return Ok(input);
+        }
+
+        let session = self.session()?;
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        self.wait_for_rate_limit()?;
+        let input = self.backend.get(&url, session)?;
+
+        // write to a temporary file first so a failed write never leaves a
+        // truncated input in the cache
+        let path = self.path(year, day);
+        let partial = path.with_extension("part");
+        fs::create_dir_all(path.parent().expect("cache path has a parent"))?;
+        fs::write(&partial, &input)?;
+        fs::rename(&partial, &path)?;
+
+        Ok(input)
+    }
+
+    /// Sleeps until `rate_limit` has passed since the last request made with
+    /// this cache directory (by any process), then records this request.
+    pub fn wait_for_rate_limit(&self) -> Result<(), InputError> {
+        let stamp = self.cache_dir.join(".last_request");
+        let now = unix_millis(SystemTime::now());
+
+        let last = fs::read_to_string(&stamp)
+            .ok()
+            .and_then(|millis| millis.trim().parse::<u128>().ok());
+        if let Some(last) = last {
+            let wait = (last + self.rate_limit.as_millis()).saturating_sub(now);
+            thread::sleep(Duration::from_millis(wait as u64));
+        }
+
+        fs::create_dir_all(&self.cache_dir)?;
+        fs::write(&stamp, unix_millis(SystemTime::now()).to_string())?;
+        Ok(())
+    }
+}
+
+/// Gets where a day's input is cached in a cache directory:
+/// `<cache_dir>/<year>/day<NN>.txt`.
+pub fn cache_path(cache_dir: &Path, year: u16, day: u8) -> PathBuf {
+    cache_dir
+        .join(year.to_string())
+        .join(format!("day{day:02}.txt"))
+}
+
+/// Finds the cache directory: `$AOC_CACHE_DIR`, the `cache_dir` config
+/// setting or `inputs/` in the workspace, in that order.
+///
+/// * `workspace_root`: the root of the workspace
+pub fn cache_dir(workspace_root: &Path) -> PathBuf {
+    env::var("AOC_CACHE_DIR")
+        .ok()
+        .or_else(|| config_value("cache_dir"))
+        .map(PathBuf::from)
+        .unwrap_or_else(|| workspace_root.join("inputs"))
+}
+
+fn unix_millis(time: SystemTime) -> u128 {
+    time.duration_since(UNIX_EPOCH)
+        .unwrap_or(Duration::ZERO)
+        .as_millis()
+}
+
+/// Gets the path of the config file: `$AOC_CONFIG`, or `aoc/config` in the
+/// user's config directory.
+pub fn config_path() -> Option<PathBuf> {
+    if let Ok(path) = env::var("AOC_CONFIG") {
+        return Some(PathBuf::from(path));
+    }
+    let config_dir = env::var("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|_| env::var("HOME").map(|home| Path::new(&home).join(".config")))
+        .ok()?;
+    Some(config_dir.join("aoc").join("config"))
+}
+
+/// Reads a `key = value` setting from the config file. Blank lines and lines
+/// starting with `#` are skipped.
+///
+/// * `key`: the setting to look for
+pub fn config_value(key: &str) -> Option<String> {
+    let config = fs::read_to_string(config_path()?).ok()?;
+    config
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| line.split_once('='))
+        .find(|(name, _)| name.trim() == key)
+        .map(|(_, value)| value.trim().to_string())
+}
+
+/// Finds the session token in `$AOC_SESSION`, falling back to the `session`
+/// setting in the config file.
+pub fn find_session() -> Option<String> {
+    env::var(SESSION_VAR)
+        .ok()
+        .map(|session| session.trim().to_string())
+        .filter(|session| !session.is_empty())
+        .or_else(|| config_value("session"))
+}
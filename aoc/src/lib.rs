@@ -0,0 +1,50 @@
+use std::{error::Error, fmt::Display};
+
+use trace::Trace;
+
+pub mod answers;
+#[cfg(feature = "bench")]
+pub mod bench;
+pub mod generate;
+pub mod grid;
+pub mod input;
+pub mod parallel;
+pub mod parse;
+pub mod pipes;
+pub mod source;
+pub mod submit;
+pub mod trace;
+
+/// The year every solved day belongs to.
+pub const YEAR: u16 = 2023;
+
+/// The error returned when a puzzle input can't be parsed.
+pub type ParseError = Box<dyn Error + Send + Sync>;
+
+/// A day's puzzle, split into parsing and the two parts so each step can be run
+/// (and timed) on its own.
+pub trait Solution {
+    /// The day of the puzzle (1-25)
+    const DAY: u8;
+
+    /// The parsed puzzle input shared by both parts
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    /// Parses the puzzle input
+    ///
+    /// * `input`: the contents of the input file
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part_one(input: &Self::Input) -> Self::Answer1;
+
+    fn part_two(input: &Self::Input) -> Self::Answer2;
+
+    /// Explains how the answers are found, for `--explain`. Days without an
+    /// explanation leave the trace empty.
+    ///
+    /// * `input`: the parsed puzzle input
+    /// * `trace`: where the explanation goes
+    fn explain(_input: &Self::Input, _trace: &mut Trace) {}
+}
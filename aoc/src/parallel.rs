@@ -0,0 +1,22 @@
+//! A switch for the days' `parallel` pieces, so a test can run them one at a
+//! time as well and check both ways give the same answers.
+
+use std::cell::Cell;
+
+thread_local! {
+    static SEQUENTIAL: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Checks whether a day built with its `parallel` feature should use rayon on
+/// this thread. It does unless it's inside `sequential`.
+pub fn enabled() -> bool {
+    !SEQUENTIAL.get()
+}
+
+/// Runs `f` with the days' `parallel` pieces run sequentially on this thread.
+pub fn sequential<T>(f: impl FnOnce() -> T) -> T {
+    let before = SEQUENTIAL.replace(true);
+    let result = f();
+    SEQUENTIAL.set(before);
+    result
+}
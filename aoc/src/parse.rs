@@ -0,0 +1,620 @@
+//! A small parser-combinator library for puzzle inputs: integers,
+//! whitespace-separated lists, labelled lines, blank-line separated sections
+//! and key/value lines.
+//!
+//! A parser is anything implementing [`Parser`], usually a closure returned by
+//! one of the functions here. Parsers always know where they are in the whole
+//! input, so errors say which line and column went wrong and [`spanned`]
+//! values can say where they came from.
+//!
+//! ```
+//! use aoc::parse::{self, integer, labelled, line, list, pair};
+//!
+//! let races = pair(
+//!     line(labelled("Time:", list(integer::<u64>()))),
+//!     line(labelled("Distance:", list(integer::<u64>()))),
+//! );
+//! let (times, distances) = parse::run(races, "Time: 7 15\nDistance: 9 40\n").unwrap();
+//! assert_eq!((times, distances), (vec![7, 15], vec![9, 40]));
+//!
+//! let err = parse::run(line(labelled("Time:", list(integer::<u8>()))), "Time: 7 300").unwrap_err();
+//! assert_eq!(err.to_string(), "line 1, column 9: number too large, found `300`");
+//! ```
+
+use std::{
+    fmt,
+    num::{IntErrorKind, ParseIntError},
+    str::FromStr,
+};
+
+/// What's left to parse: the rest of the input up to the end of the current
+/// line or section, and where that is in the whole input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Input<'a> {
+    source: &'a str,
+    pos: usize,
+    end: usize,
+    /// 1-based
+    line: usize,
+    line_start: usize,
+}
+
+impl<'a> Input<'a> {
+    pub fn new(source: &'a str) -> Self {
+        Input {
+            source,
+            pos: 0,
+            end: source.len(),
+            line: 1,
+            line_start: 0,
+        }
+    }
+
+    /// Gets the text left to parse.
+    pub fn rest(&self) -> &'a str {
+        &self.source[self.pos..self.end]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pos == self.end
+    }
+
+    /// Moves past the next `len` bytes.
+    fn advance(mut self, len: usize) -> Self {
+        let skipped = &self.source[self.pos..self.pos + len];
+        if let Some(newline) = skipped.rfind('\n') {
+            self.line += skipped.matches('\n').count();
+            self.line_start = self.pos + newline + 1;
+        }
+        self.pos += len;
+        self
+    }
+
+    /// Limits the input to its next `len` bytes.
+    fn limit(mut self, len: usize) -> Self {
+        self.end = self.pos + len;
+        self
+    }
+
+    /// Gets the span of the next `len` bytes.
+    fn span(&self, len: usize) -> Span {
+        Span {
+            start: self.pos,
+            end: self.pos + len,
+            line: self.line,
+            column: self.source[self.line_start..self.pos].chars().count() + 1,
+        }
+    }
+
+    /// Gets the span from here to `later`, which has to be on the same input.
+    fn span_to(&self, later: &Input<'a>) -> Span {
+        self.span(later.pos - self.pos)
+    }
+
+    fn error(&self, kind: ErrorKind) -> Error {
+        self.error_spanning(self.span(0), kind)
+    }
+
+    fn error_spanning(&self, span: Span, kind: ErrorKind) -> Error {
+        // what was there instead: the span, or the next word if it's empty
+        let after = &self.source[span.start..];
+        let found = match &self.source[span.start..span.end] {
+            "" if after.is_empty() => Found::EndOfInput,
+            "" if after.starts_with(['\n', '\r']) => Found::EndOfLine,
+            "" => {
+                let word = after.find(char::is_whitespace).unwrap_or(after.len());
+                let len = after.chars().next().map_or(0, char::len_utf8);
+                Found::Text(after[..word.max(len)].to_string())
+            }
+            text => Found::Text(text.to_string()),
+        };
+        let found = match found {
+            Found::Text(text) if text.chars().count() > 20 => {
+                Found::Text(text.chars().take(20).collect::<String>() + "...")
+            }
+            found => found,
+        };
+        Error { span, kind, found }
+    }
+}
+
+/// Where something is in the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Span {
+    /// byte offset of the start
+    pub start: usize,
+    /// byte offset one past the end
+    pub end: usize,
+    /// 1-based line of the start
+    pub line: usize,
+    /// 1-based column (in characters) of the start
+    pub column: usize,
+}
+
+impl Span {
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Gets the spanned text.
+    ///
+    /// * `source`: the whole input the span is in
+    pub fn text<'a>(&self, source: &'a str) -> &'a str {
+        &source[self.start..self.end]
+    }
+}
+
+/// A parsed value and where it came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Spanned<T> {
+    pub value: T,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// This exact text should have been next
+    Tag(&'static str),
+    /// Something else should have been next, e.g. "number"
+    Expected(&'static str),
+    NumberTooLarge,
+    /// More input after everything was parsed
+    Unexpected,
+    /// The text parsed but doesn't make sense, e.g. an unknown colour
+    Invalid(String),
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::Tag(tag) => write!(f, "expected `{tag}`"),
+            ErrorKind::Expected(what) => write!(f, "expected {what}"),
+            ErrorKind::NumberTooLarge => write!(f, "number too large"),
+            ErrorKind::Unexpected => write!(f, "unexpected input"),
+            ErrorKind::Invalid(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+/// What was found where an error happened.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Found {
+    Text(String),
+    EndOfLine,
+    EndOfInput,
+}
+
+impl fmt::Display for Found {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Found::Text(text) => write!(f, "`{text}`"),
+            Found::EndOfLine => write!(f, "the end of the line"),
+            Found::EndOfInput => write!(f, "the end of the input"),
+        }
+    }
+}
+
+/// Why and where a parser failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    pub span: Span,
+    pub kind: ErrorKind,
+    pub found: Found,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.span.line, self.span.column, self.kind
+        )?;
+        match self.kind {
+            ErrorKind::Invalid(_) => Ok(()),
+            _ => write!(f, ", found {}", self.found),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A parser's result: the value and the input left after it.
+pub type Parsed<'a, T> = Result<(T, Input<'a>), Error>;
+
+/// Something that parses a `T` from the start of an input.
+pub trait Parser<'a, T> {
+    fn parse(&self, input: Input<'a>) -> Parsed<'a, T>;
+}
+
+impl<'a, T, F> Parser<'a, T> for F
+where
+    F: Fn(Input<'a>) -> Parsed<'a, T>,
+{
+    fn parse(&self, input: Input<'a>) -> Parsed<'a, T> {
+        self(input)
+    }
+}
+
+/// Parses the whole of `source`: anything left over is an error.
+///
+/// * `parser`: what `source` should be
+/// * `source`: the text to parse
+pub fn run<'a, T>(parser: impl Parser<'a, T>, source: &'a str) -> Result<T, Error> {
+    all(parser)
+        .parse(Input::new(source))
+        .map(|(value, _)| value)
+}
+
+/// Checks whether a parser failed after reading something (e.g. a number that
+/// turned out too large), rather than at the start. That's a real error,
+/// instead of a sign to try something else.
+fn got_further(err: &Error, input: &Input<'_>) -> bool {
+    err.span.end > input.pos
+}
+
+// --- text ---
+
+/// Parses some exact text.
+pub fn tag<'a>(tag: &'static str) -> impl Parser<'a, &'a str> {
+    move |input: Input<'a>| match input.rest().starts_with(tag) {
+        true => Ok((tag, input.advance(tag.len()))),
+        false => Err(input.error(ErrorKind::Tag(tag))),
+    }
+}
+
+/// Parses the longest run of characters matching `predicate`, which can be
+/// empty.
+pub fn take_while<'a>(predicate: impl Fn(char) -> bool) -> impl Parser<'a, &'a str> {
+    move |input: Input<'a>| {
+        let rest = input.rest();
+        let len = rest.find(|c| !predicate(c)).unwrap_or(rest.len());
+        Ok((&rest[..len], input.advance(len)))
+    }
+}
+
+/// Parses the longest run of characters matching `predicate`, which has to be
+/// at least one character.
+///
+/// * `what`: what the characters are, for errors
+pub fn take_while1<'a>(
+    what: &'static str,
+    predicate: impl Fn(char) -> bool,
+) -> impl Parser<'a, &'a str> {
+    let many = take_while(predicate);
+    move |input: Input<'a>| match many.parse(input)? {
+        ("", _) => Err(input.error(ErrorKind::Expected(what))),
+        parsed => Ok(parsed),
+    }
+}
+
+/// Parses one character matching `predicate`, e.g. the suit of `Kh`.
+///
+/// * `what`: what the character is, for errors
+pub fn char_if<'a>(what: &'static str, predicate: impl Fn(char) -> bool) -> impl Parser<'a, char> {
+    move |input: Input<'a>| match input.rest().chars().next() {
+        Some(c) if predicate(c) => Ok((c, input.advance(c.len_utf8()))),
+        _ => Err(input.error(ErrorKind::Expected(what))),
+    }
+}
+
+/// Parses letters, digits and underscores, e.g. `AAA` or `32T3K`.
+pub fn word<'a>() -> impl Parser<'a, &'a str> {
+    take_while1("a word", |c| c.is_alphanumeric() || c == '_')
+}
+
+/// Skips any spaces and tabs.
+pub fn spaces<'a>() -> impl Parser<'a, ()> {
+    map(take_while(|c| c == ' ' || c == '\t'), |_| ())
+}
+
+/// Checks that there's nothing left of the current line or section.
+pub fn end<'a>() -> impl Parser<'a, ()> {
+    |input: Input<'a>| match input.is_empty() {
+        true => Ok(((), input)),
+        false => Err(input.error(ErrorKind::Expected("the end of the line"))),
+    }
+}
+
+/// Parses everything up to the end of the current line or section.
+pub fn rest<'a>() -> impl Parser<'a, &'a str> {
+    |input: Input<'a>| Ok((input.rest(), input.advance(input.rest().len())))
+}
+
+// --- numbers ---
+
+/// Parses ASCII digits, without a sign.
+pub fn digits<'a>() -> impl Parser<'a, &'a str> {
+    take_while1("a number", |c| c.is_ascii_digit())
+}
+
+/// Parses a number without a sign.
+pub fn unsigned<'a, T>() -> impl Parser<'a, T>
+where
+    T: FromStr<Err = ParseIntError>,
+{
+    number(digits())
+}
+
+/// Parses a number, negative if it starts with `-`. Unsigned types don't
+/// accept the `-`.
+pub fn integer<'a, T>() -> impl Parser<'a, T>
+where
+    T: FromStr<Err = ParseIntError>,
+{
+    number(recognize(pair(opt(tag("-")), digits())))
+}
+
+/// Converts the text of a number, with an error spanning it if it doesn't fit.
+fn number<'a, T>(text: impl Parser<'a, &'a str>) -> impl Parser<'a, T>
+where
+    T: FromStr<Err = ParseIntError>,
+{
+    move |input: Input<'a>| {
+        let (digits, rest) = text.parse(input)?;
+        match digits.parse::<T>() {
+            Ok(number) => Ok((number, rest)),
+            Err(err) => match err.kind() {
+                IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => {
+                    Err(input.error_spanning(input.span_to(&rest), ErrorKind::NumberTooLarge))
+                }
+                // only a `-` for an unsigned type gets this far
+                _ => Err(input.error(ErrorKind::Expected("a number without a sign"))),
+            },
+        }
+    }
+}
+
+// --- combinators ---
+
+/// Changes what a parser returns.
+pub fn map<'a, T, U>(parser: impl Parser<'a, T>, f: impl Fn(T) -> U) -> impl Parser<'a, U> {
+    move |input: Input<'a>| {
+        let (value, rest) = parser.parse(input)?;
+        Ok((f(value), rest))
+    }
+}
+
+/// Checks and changes what a parser returns. An error message from `f` becomes
+/// an error spanning everything the parser read.
+pub fn try_map<'a, T, U>(
+    parser: impl Parser<'a, T>,
+    f: impl Fn(T) -> Result<U, String>,
+) -> impl Parser<'a, U> {
+    move |input: Input<'a>| {
+        let (value, rest) = parser.parse(input)?;
+        match f(value) {
+            Ok(value) => Ok((value, rest)),
+            Err(message) => {
+                Err(input.error_spanning(input.span_to(&rest), ErrorKind::Invalid(message)))
+            }
+        }
+    }
+}
+
+/// Keeps where a parser's value came from.
+pub fn spanned<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, Spanned<T>> {
+    move |input: Input<'a>| {
+        let (value, rest) = parser.parse(input)?;
+        let span = input.span_to(&rest);
+        Ok((Spanned { value, span }, rest))
+    }
+}
+
+/// Gets the text a parser read instead of its value.
+pub fn recognize<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, &'a str> {
+    move |input: Input<'a>| {
+        let (_, rest) = parser.parse(input)?;
+        Ok((&input.rest()[..rest.pos - input.pos], rest))
+    }
+}
+
+/// Parses one thing then another.
+pub fn pair<'a, A, B>(
+    first: impl Parser<'a, A>,
+    second: impl Parser<'a, B>,
+) -> impl Parser<'a, (A, B)> {
+    move |input: Input<'a>| {
+        let (a, rest) = first.parse(input)?;
+        let (b, rest) = second.parse(rest)?;
+        Ok(((a, b), rest))
+    }
+}
+
+/// Parses `first` then `second`, keeping only `second`.
+pub fn preceded<'a, A, B>(
+    first: impl Parser<'a, A>,
+    second: impl Parser<'a, B>,
+) -> impl Parser<'a, B> {
+    map(pair(first, second), |(_, b)| b)
+}
+
+/// Parses `first` then `second`, keeping only `first`.
+pub fn terminated<'a, A, B>(
+    first: impl Parser<'a, A>,
+    second: impl Parser<'a, B>,
+) -> impl Parser<'a, A> {
+    map(pair(first, second), |(a, _)| a)
+}
+
+/// Parses something between two other things, e.g. `(` and `)`.
+pub fn delimited<'a, A, T, B>(
+    open: impl Parser<'a, A>,
+    parser: impl Parser<'a, T>,
+    close: impl Parser<'a, B>,
+) -> impl Parser<'a, T> {
+    preceded(open, terminated(parser, close))
+}
+
+/// Parses something that might not be there. It's still an error if it fails
+/// partway through.
+pub fn opt<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, Option<T>> {
+    move |input: Input<'a>| match parser.parse(input) {
+        Ok((value, rest)) => Ok((Some(value), rest)),
+        Err(err) if got_further(&err, &input) => Err(err),
+        Err(_) => Ok((None, input)),
+    }
+}
+
+/// Tries `first`, then `second` if it fails without getting anywhere. If both
+/// fail, the error from whichever got further is kept (`first`'s on a tie).
+pub fn alt<'a, T>(first: impl Parser<'a, T>, second: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    move |input: Input<'a>| match first.parse(input) {
+        Err(err) if !got_further(&err, &input) => match second.parse(input) {
+            Err(other) if other.span.end <= err.span.end => Err(err),
+            parsed => parsed,
+        },
+        parsed => parsed,
+    }
+}
+
+/// Parses something as many times as it's there, which can be none. An
+/// attempt that fails partway through is an error.
+pub fn many<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    move |mut input: Input<'a>| {
+        let mut values = vec![];
+        loop {
+            match parser.parse(input) {
+                // stop anything that doesn't read anything looping forever
+                Ok((_, rest)) if rest.pos == input.pos => return Ok((values, input)),
+                Ok((value, rest)) => {
+                    values.push(value);
+                    input = rest;
+                }
+                Err(err) if got_further(&err, &input) => return Err(err),
+                Err(_) => return Ok((values, input)),
+            }
+        }
+    }
+}
+
+/// Parses one or more things with a separator between them, e.g. `a, b, c`.
+pub fn separated<'a, T, S>(
+    item: impl Parser<'a, T>,
+    separator: impl Parser<'a, S>,
+) -> impl Parser<'a, Vec<T>> {
+    move |input: Input<'a>| {
+        let (first, mut input) = item.parse(input)?;
+        let mut values = vec![first];
+        loop {
+            match separator.parse(input) {
+                Ok((_, rest)) => {
+                    let (value, rest) = item.parse(rest)?;
+                    values.push(value);
+                    input = rest;
+                }
+                Err(err) if got_further(&err, &input) => return Err(err),
+                Err(_) => return Ok((values, input)),
+            }
+        }
+    }
+}
+
+/// Parses a list of things separated by spaces, like `41 48 83 86 17`. The
+/// list can be empty, and spaces around it are skipped.
+pub fn list<'a, T>(item: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    delimited(spaces(), many(terminated(item, spaces())), spaces())
+}
+
+/// Parses a label followed by a value, like `Time:      7  15   30`.
+pub fn labelled<'a, T>(label: &'static str, value: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    preceded(pair(tag(label), spaces()), value)
+}
+
+/// Parses a key and a value with a separator between them, like
+/// `AAA = (BBB, CCC)`. The separator can have spaces around it.
+pub fn key_value<'a, K, V>(
+    key: impl Parser<'a, K>,
+    separator: &'static str,
+    value: impl Parser<'a, V>,
+) -> impl Parser<'a, (K, V)> {
+    pair(
+        key,
+        preceded(delimited(spaces(), tag(separator), spaces()), value),
+    )
+}
+
+/// Runs a parser that has to use up all of its input.
+fn all<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    move |input: Input<'a>| {
+        let (value, rest) = parser.parse(input)?;
+        match rest.is_empty() {
+            true => Ok((value, rest)),
+            false => Err(rest.error_spanning(rest.span(rest.rest().len()), ErrorKind::Unexpected)),
+        }
+    }
+}
+
+// --- lines and sections ---
+
+/// Parses one line, which has to be all `parser`, and the line break after it.
+pub fn line<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    let parser = all(parser);
+    move |input: Input<'a>| {
+        let rest = input.rest();
+        let (len, newline) = match rest.find('\n') {
+            Some(end) => (end, 1),
+            None => (rest.len(), 0),
+        };
+        // the `\r` of a `\r\n` isn't part of the line
+        let content = rest[..len].strip_suffix('\r').map_or(len, str::len);
+
+        let (value, _) = parser.parse(input.limit(content))?;
+        Ok((value, input.advance(len + newline)))
+    }
+}
+
+/// Parses every line up to the end of the input or section.
+pub fn lines<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    let line = line(parser);
+    move |mut input: Input<'a>| {
+        let mut values = vec![];
+        while !input.is_empty() {
+            let (value, rest) = line.parse(input)?;
+            values.push(value);
+            input = rest;
+        }
+        Ok((values, input))
+    }
+}
+
+/// Parses a section: the lines up to the next blank line, which all have to be
+/// `parser`. The blank lines after it are skipped.
+pub fn section<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    let parser = all(parser);
+    move |input: Input<'a>| {
+        let rest = input.rest();
+        let is_blank = |line: &str| line.trim_end_matches('\r').is_empty();
+
+        let len: usize = rest
+            .split_inclusive('\n')
+            .take_while(|line| !is_blank(line.trim_end_matches('\n')))
+            .map(str::len)
+            .sum();
+        let blank: usize = rest[len..]
+            .split_inclusive('\n')
+            .take_while(|line| is_blank(line.trim_end_matches('\n')))
+            .map(str::len)
+            .sum();
+
+        let (value, _) = parser.parse(input.limit(len))?;
+        Ok((value, input.advance(len + blank)))
+    }
+}
+
+/// Parses every section up to the end of the input.
+pub fn sections<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    let section = section(parser);
+    move |mut input: Input<'a>| {
+        let mut values = vec![];
+        while !input.is_empty() {
+            let (value, rest) = section.parse(input)?;
+            values.push(value);
+            input = rest;
+        }
+        Ok((values, input))
+    }
+}
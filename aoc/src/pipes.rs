@@ -0,0 +1,80 @@
+//! Day 10's pipes: the two directions each one connects, and following a loop
+//! of them. Shared by the solver and `generate::pipe_maze`, so the mazes are
+//! drawn with the same pipes they're read with.
+
+use crate::grid::{Direction, Pos};
+
+/// Every pipe, with the two directions it connects.
+pub const PIPES: [(char, [Direction; 2]); 6] = [
+    ('|', [Direction::North, Direction::South]),
+    ('-', [Direction::East, Direction::West]),
+    ('L', [Direction::North, Direction::East]),
+    ('J', [Direction::North, Direction::West]),
+    ('7', [Direction::South, Direction::West]),
+    ('F', [Direction::East, Direction::South]),
+];
+
+/// Gets the directions a pipe connects, or `None` for ground (`.`) and `S`.
+pub fn connections(pipe: char) -> Option<[Direction; 2]> {
+    PIPES
+        .iter()
+        .find(|(char, _)| *char == pipe)
+        .map(|(_, directions)| *directions)
+}
+
+/// Gets the pipe connecting two (different) directions.
+pub fn pipe_between(one: Direction, two: Direction) -> char {
+    PIPES
+        .iter()
+        .find(|(_, directions)| directions.contains(&one) && directions.contains(&two))
+        .map(|(char, _)| *char)
+        .expect("every two directions have a pipe")
+}
+
+/// Where following a loop went wrong.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WalkError {
+    /// The pipe at this tile leads off the grid
+    LeavesGrid(Pos),
+    /// This tile doesn't connect back to the pipe before it
+    Breaks(Pos),
+}
+
+/// Follows the pipes from `start` until the walk comes back to it, giving the
+/// tiles in order, `start` first.
+///
+/// * `start`: where the loop starts, whatever is there
+/// * `direction`: the way out of `start`
+/// * `tile`: the character at a tile, `None` outside the grid
+pub fn walk_loop(
+    start: Pos,
+    direction: Direction,
+    tile: impl Fn(Pos) -> Option<char>,
+) -> Result<Vec<Pos>, WalkError> {
+    let mut path = vec![start];
+    let (mut pos, mut direction) = (start, direction);
+    loop {
+        let (rows, cols) = direction.offset();
+        let next = pos
+            .0
+            .checked_add_signed(rows)
+            .zip(pos.1.checked_add_signed(cols))
+            .filter(|&next| tile(next).is_some())
+            .ok_or(WalkError::LeavesGrid(pos))?;
+        if next == start {
+            return Ok(path);
+        }
+        // every pipe has two ends, so a walk that never breaks comes back to
+        // `start` without visiting a tile twice
+        let [one, two] = tile(next)
+            .and_then(connections)
+            .filter(|ends| ends.contains(&direction.opposite()))
+            .ok_or(WalkError::Breaks(next))?;
+        direction = match one == direction.opposite() {
+            true => two,
+            false => one,
+        };
+        path.push(next);
+        pos = next;
+    }
+}
@@ -0,0 +1,221 @@
+//! Where a solver reads its puzzle input from: a file, stdin, a string given
+//! on the command line, or the day's usual input.
+
+use std::{
+    fmt, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    process,
+};
+
+use crate::{input, trace, YEAR};
+
+/// A puzzle input picked on the command line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// A file
+    Path(PathBuf),
+    /// Standard input, picked with `-`
+    Stdin,
+    /// The input itself, given with `--input-str`
+    Inline(String),
+    /// The day's `input.txt`, or its cached input if there isn't one
+    Default,
+}
+
+#[derive(Debug)]
+pub enum SourceError {
+    /// The arguments don't pick a source
+    Usage(String),
+    Read {
+        path: PathBuf,
+        err: io::Error,
+    },
+    Stdin(io::Error),
+    /// The day has no `input.txt` and nothing cached
+    Missing {
+        day: u8,
+        local: PathBuf,
+        cached: PathBuf,
+    },
+}
+
+impl fmt::Display for SourceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SourceError::Usage(message) => write!(f, "{message}"),
+            SourceError::Read { path, err } => {
+                write!(f, "couldn't read input file {}: {err}", path.display())
+            }
+            SourceError::Stdin(err) => write!(f, "couldn't read input from stdin: {err}"),
+            SourceError::Missing { day, local, cached } => write!(
+                f,
+                "no input for day {day}: save it as {} or fetch it into {} with \
+                 `cargo run -p runner -- fetch {day}`",
+                local.display(),
+                cached.display()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SourceError {}
+
+impl InputSource {
+    /// Picks the source from command line arguments: a path, `-` for stdin,
+    /// `--input-str <input>`, or nothing for the day's usual input. The other
+    /// `--` flags have to be in `flags` and are handed back for the caller to
+    /// handle. A flag ending in `=` takes a value, given as `--flag=value` or
+    /// `--flag value` and handed back as `--flag=value`.
+    ///
+    /// * `args`: the arguments, without the program name
+    /// * `flags`: the flags the program takes, e.g. `["--check", "--html="]`
+    pub fn from_args(
+        args: impl IntoIterator<Item = String>,
+        flags: &[&str],
+    ) -> Result<(Self, Vec<String>), SourceError> {
+        let mut source = InputSource::Default;
+        let mut rest = vec![];
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let picked = match arg.as_str() {
+                "--input-str" => InputSource::Inline(args.next().ok_or_else(|| {
+                    SourceError::Usage("--input-str needs the input after it".to_string())
+                })?),
+                "-" => InputSource::Stdin,
+                flag if flag.starts_with("--") => {
+                    rest.push(known_flag(flag, flags, &mut args)?);
+                    continue;
+                }
+                path => InputSource::Path(PathBuf::from(path)),
+            };
+            if source != InputSource::Default {
+                return Err(SourceError::Usage(
+                    "only one input can be given (a path, `-` or --input-str)".to_string(),
+                ));
+            }
+            source = picked;
+        }
+
+        Ok((source, rest))
+    }
+
+    /// Reads the input.
+    ///
+    /// * `day`: the day the input is for
+    /// * `dir`: the day's crate directory, where its `input.txt` is
+    pub fn read(&self, day: u8, dir: &Path) -> Result<String, SourceError> {
+        match self {
+            InputSource::Path(path) => fs::read_to_string(path).map_err(|err| SourceError::Read {
+                path: path.clone(),
+                err,
+            }),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(SourceError::Stdin)?;
+                Ok(input)
+            }
+            InputSource::Inline(input) => Ok(input.clone()),
+            InputSource::Default => read_default(day, dir),
+        }
+    }
+}
+
+/// Reads a day's usual input: its `input.txt`, unless that's missing or empty,
+/// then the cached input. Nothing is fetched.
+///
+/// * `day`: the day the input is for
+/// * `dir`: the day's crate directory, inside the workspace
+pub fn read_default(day: u8, dir: &Path) -> Result<String, SourceError> {
+    let local = dir.join("input.txt");
+    match fs::read_to_string(&local) {
+        Ok(input) if !input.is_empty() => return Ok(input),
+        Ok(_) => {}
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+        Err(err) => return Err(SourceError::Read { path: local, err }),
+    }
+
+    // days live in `<workspace>/day<N>/rust_sol`
+    let workspace_root = dir.ancestors().nth(2).unwrap_or(dir);
+    let cached = input::cache_path(&input::cache_dir(workspace_root), YEAR, day);
+    match fs::read_to_string(&cached) {
+        Ok(input) => Ok(input),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            Err(SourceError::Missing { day, local, cached })
+        }
+        Err(err) => Err(SourceError::Read { path: cached, err }),
+    }
+}
+
+/// Checks a flag is one of `flags`, taking the next argument as its value if
+/// it takes one and doesn't have it yet.
+fn known_flag(
+    flag: &str,
+    flags: &[&str],
+    args: &mut impl Iterator<Item = String>,
+) -> Result<String, SourceError> {
+    let takes_value = |name: &str| {
+        flags
+            .iter()
+            .any(|known| known.strip_suffix('=') == Some(name))
+    };
+    match flag.split_once('=') {
+        None if flags.contains(&flag) => Ok(flag.to_string()),
+        None if takes_value(flag) => match args.next() {
+            Some(value) => Ok(format!("{flag}={value}")),
+            None => Err(SourceError::Usage(format!("{flag} needs a value after it"))),
+        },
+        Some((name, "")) if takes_value(name) => {
+            Err(SourceError::Usage(format!("{name} needs a value after it")))
+        }
+        Some(_) if flags.contains(&flag) => Ok(flag.to_string()),
+        Some((name, _)) if takes_value(name) => Ok(flag.to_string()),
+        _ => Err(SourceError::Usage(format!("unknown flag `{flag}`"))),
+    }
+}
+
+/// Gets a day binary's usage line, e.g.
+/// `usage: day3 [path | - | --input-str <input>] [--render] [--html=<value>]`.
+///
+/// * `day`: the day
+/// * `flags`: the flags it takes, as given to `InputSource::from_args`
+pub fn usage(day: u8, flags: &[&str]) -> String {
+    let mut usage = format!("usage: day{day} [path | - | --input-str <input>]");
+    for flag in flags {
+        match flag.ends_with('=') {
+            true => usage.push_str(&format!(" [{flag}<value>]")),
+            false => usage.push_str(&format!(" [{flag}]")),
+        }
+    }
+    usage
+}
+
+/// Reads the input picked by the program's arguments, for a day's `main`.
+/// Prints the error (with the usage for a bad argument) and exits if it
+/// can't. Returns the input and the flags that weren't about the input.
+/// Every day also takes `--explain` and `--explain-limit`, see
+/// `trace::print_if_asked`.
+///
+/// * `day`: the day the input is for
+/// * `dir`: the day's crate directory, i.e. `env!("CARGO_MANIFEST_DIR")`
+/// * `flags`: the day's own flags, see `InputSource::from_args`
+pub fn read_or_exit(day: u8, dir: &str, flags: &[&str]) -> (String, Vec<String>) {
+    let flags: Vec<&str> = flags.iter().chain(trace::FLAGS).copied().collect();
+    let read =
+        InputSource::from_args(std::env::args().skip(1), &flags).and_then(|(source, rest)| {
+            // a bad limit is found before solving rather than after
+            trace::limit_from_flags(&rest).map_err(SourceError::Usage)?;
+            Ok((source.read(day, Path::new(dir))?, rest))
+        });
+
+    read.unwrap_or_else(|err| {
+        match err {
+            SourceError::Usage(_) => eprintln!("{err}\n{}", usage(day, &flags)),
+            _ => eprintln!("{err}"),
+        }
+        process::exit(1);
+    })
+}
@@ -0,0 +1,322 @@
+//! Submitting answers to the website, and remembering how every guess went so
+//! a known-wrong answer is never sent twice.
+
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::input::{HttpBackend, InputError, InputManager};
+
+/// What the website said about a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without saying which way
+    Wrong,
+    /// Answered too recently, with how long is left to wait if it said
+    Wait(Option<Duration>),
+    /// The part has already been solved
+    AlreadySolved,
+    /// A response that couldn't be understood
+    Unknown,
+}
+
+impl Verdict {
+    /// Reads the verdict out of the page returned after submitting.
+    pub fn from_response(body: &str) -> Self {
+        if body.contains("That's the right answer") {
+            Verdict::Correct
+        } else if body.contains("too high") {
+            Verdict::TooHigh
+        } else if body.contains("too low") {
+            Verdict::TooLow
+        } else if body.contains("That's not the right answer") {
+            Verdict::Wrong
+        } else if body.contains("You gave an answer too recently") {
+            Verdict::Wait(parse_wait(body))
+        } else if body.contains("Did you already complete it") {
+            Verdict::AlreadySolved
+        } else {
+            Verdict::Unknown
+        }
+    }
+
+    /// Gets the name stored in history files, for verdicts worth storing.
+    fn label(&self) -> Option<&'static str> {
+        match self {
+            Verdict::Correct => Some("correct"),
+            Verdict::TooHigh => Some("too_high"),
+            Verdict::TooLow => Some("too_low"),
+            Verdict::Wrong => Some("wrong"),
+            _ => None,
+        }
+    }
+
+    fn from_label(label: &str) -> Option<Self> {
+        match label {
+            "correct" => Some(Verdict::Correct),
+            "too_high" => Some(Verdict::TooHigh),
+            "too_low" => Some(Verdict::TooLow),
+            "wrong" => Some(Verdict::Wrong),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "wrong, too high"),
+            Verdict::TooLow => write!(f, "wrong, too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::Wait(Some(left)) => write!(f, "too soon, wait {}s", left.as_secs()),
+            Verdict::Wait(None) => write!(f, "too soon, wait a bit"),
+            Verdict::AlreadySolved => write!(f, "already solved"),
+            Verdict::Unknown => write!(f, "couldn't understand the response"),
+        }
+    }
+}
+
+/// Reads "You have 1m 5s left to wait" into a duration.
+fn parse_wait(body: &str) -> Option<Duration> {
+    let start = body.find("You have ")? + "You have ".len();
+    let end = start + body[start..].find(" left")?;
+
+    body[start..end]
+        .split_whitespace()
+        .map(|part| {
+            let (number, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+            let number: u64 = number.parse().ok()?;
+            match unit {
+                "s" => Some(number),
+                "m" => Some(number * 60),
+                "h" => Some(number * 60 * 60),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+/// One submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Why an answer wasn't sent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    /// The part is already solved, with this answer
+    AlreadySolved(String),
+    /// This exact answer was already sent and was wrong
+    KnownWrong(Verdict),
+    /// An answer at least this big was already too high
+    AboveBound(String),
+    /// An answer at least this small was already too low
+    BelowBound(String),
+    Empty,
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Refusal::AlreadySolved(answer) => write!(f, "already solved, the answer was {answer}"),
+            Refusal::KnownWrong(verdict) => write!(f, "already tried, it was {verdict}"),
+            Refusal::AboveBound(bound) => write!(f, "{bound} was already too high"),
+            Refusal::BelowBound(bound) => write!(f, "{bound} was already too low"),
+            Refusal::Empty => write!(f, "the answer is empty"),
+        }
+    }
+}
+
+/// Every answer submitted for one day, stored one per line as
+/// `<part>\t<answer>\t<verdict>`.
+#[derive(Debug)]
+pub struct History {
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+impl History {
+    /// Loads a history file, which is empty if it doesn't exist yet. Lines that
+    /// can't be read are skipped.
+    pub fn load(path: impl Into<PathBuf>) -> io::Result<Self> {
+        let path = path.into();
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(err),
+        };
+
+        let attempts = contents
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.split('\t');
+                Some(Attempt {
+                    part: fields.next()?.parse().ok()?,
+                    answer: fields.next()?.to_string(),
+                    verdict: Verdict::from_label(fields.next()?)?,
+                })
+            })
+            .collect();
+
+        Ok(History { path, attempts })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn attempts(&self) -> &[Attempt] {
+        &self.attempts
+    }
+
+    /// Gets the accepted answer for a part, if there is one
+    pub fn correct(&self, part: u8) -> Option<&str> {
+        self.attempts
+            .iter()
+            .find(|attempt| attempt.part == part && attempt.verdict == Verdict::Correct)
+            .map(|attempt| attempt.answer.as_str())
+    }
+
+    /// Gets the biggest answer known to be too low and the smallest known to
+    /// be too high, for numeric answers.
+    pub fn bounds(&self, part: u8) -> (Option<i128>, Option<i128>) {
+        let numeric = |verdict: Verdict| {
+            self.attempts
+                .iter()
+                .filter(move |attempt| attempt.part == part && attempt.verdict == verdict)
+                .filter_map(|attempt| attempt.answer.parse::<i128>().ok())
+        };
+        (
+            numeric(Verdict::TooLow).max(),
+            numeric(Verdict::TooHigh).min(),
+        )
+    }
+
+    /// Checks whether an answer is worth submitting.
+    ///
+    /// * `part`: 1 or 2
+    /// * `answer`: the answer to check
+    pub fn check(&self, part: u8, answer: &str) -> Result<(), Refusal> {
+        if answer.trim().is_empty() {
+            return Err(Refusal::Empty);
+        }
+        if let Some(correct) = self.correct(part) {
+            return Err(Refusal::AlreadySolved(correct.to_string()));
+        }
+        if let Some(attempt) = self
+            .attempts
+            .iter()
+            .find(|attempt| attempt.part == part && attempt.answer == answer)
+        {
+            return Err(Refusal::KnownWrong(attempt.verdict.clone()));
+        }
+
+        let (too_low, too_high) = self.bounds(part);
+        if let Ok(number) = answer.parse::<i128>() {
+            if let Some(high) = too_high.filter(|high| number >= *high) {
+                return Err(Refusal::AboveBound(high.to_string()));
+            }
+            if let Some(low) = too_low.filter(|low| number <= *low) {
+                return Err(Refusal::BelowBound(low.to_string()));
+            }
+        }
+        Ok(())
+    }
+
+    /// Adds an answer to the history and saves it. Verdicts that don't say
+    /// anything about the answer (e.g. having to wait) aren't kept.
+    pub fn record(&mut self, part: u8, answer: &str, verdict: &Verdict) -> io::Result<()> {
+        let Some(label) = verdict.label() else {
+            return Ok(());
+        };
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut contents = fs::read_to_string(&self.path).unwrap_or_default();
+        contents += &format!("{part}\t{answer}\t{label}\n");
+        fs::write(&self.path, contents)?;
+
+        self.attempts.push(Attempt {
+            part,
+            answer: answer.to_string(),
+            verdict: verdict.clone(),
+        });
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    Refused(Refusal),
+    Input(InputError),
+}
+
+impl fmt::Display for SubmitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmitError::Refused(refusal) => write!(f, "not submitted: {refusal}"),
+            SubmitError::Input(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for SubmitError {}
+
+impl From<InputError> for SubmitError {
+    fn from(err: InputError) -> Self {
+        SubmitError::Input(err)
+    }
+}
+
+impl From<io::Error> for SubmitError {
+    fn from(err: io::Error) -> Self {
+        SubmitError::Input(InputError::Io(err))
+    }
+}
+
+/// Gets where a day's submission history is kept: next to its cached input.
+pub fn history_path<B: HttpBackend>(manager: &InputManager<B>, year: u16, day: u8) -> PathBuf {
+    manager.path(year, day).with_extension("history")
+}
+
+/// Submits an answer unless the history says it's wrong, and records the
+/// verdict. Uses the manager's site, session token and rate limit.
+///
+/// * `manager`: the input manager for the same site
+/// * `part`: 1 or 2
+/// * `answer`: the answer to submit
+pub fn submit<B: HttpBackend>(
+    manager: &InputManager<B>,
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<Verdict, SubmitError> {
+    let answer = answer.trim();
+    let mut history = History::load(history_path(manager, year, day))?;
+    history.check(part, answer).map_err(SubmitError::Refused)?;
+
+    let session = manager.session()?;
+    let url = format!("{}/{year}/day/{day}/answer", manager.base_url());
+    let level = part.to_string();
+    manager.wait_for_rate_limit()?;
+    let body =
+        manager
+            .backend()
+            .post_form(&url, session, &[("level", &level), ("answer", answer)])?;
+
+    let verdict = Verdict::from_response(&body);
+    history.record(part, answer, &verdict)?;
+    Ok(verdict)
+}
@@ -0,0 +1,120 @@
+//! Traces of how a day found its answers, for `--explain`: one line per step
+//! (a line of input, a game, a seed...), up to a limit.
+
+use std::{
+    fmt::{self, Display},
+    process,
+};
+
+use crate::Solution;
+
+/// The flags every day's binary takes for `--explain`, on top of its own.
+pub const FLAGS: &[&str] = &["--explain", "--explain-limit="];
+
+/// How many lines an explanation keeps unless `--explain-limit` says
+/// otherwise.
+pub const DEFAULT_LIMIT: usize = 100;
+
+/// The lines of an explanation. Lines past the limit are only counted, so a
+/// day can explain every step of a big input without flooding the terminal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trace {
+    limit: usize,
+    lines: Vec<String>,
+    skipped: usize,
+}
+
+impl Trace {
+    /// Starts an empty trace.
+    ///
+    /// * `limit`: how many lines to keep
+    pub fn new(limit: usize) -> Self {
+        Trace {
+            limit,
+            lines: vec![],
+            skipped: 0,
+        }
+    }
+
+    /// Adds a line, or counts it as skipped once the trace is full. Takes
+    /// `format_args!` so skipped lines are never formatted.
+    pub fn line(&mut self, line: impl Display) {
+        match self.is_full() {
+            true => self.skipped += 1,
+            false => self.lines.push(line.to_string()),
+        }
+    }
+
+    /// Adds a heading, e.g. `part two`, so the lines under it can be told
+    /// apart. Headings count towards the limit like any other line.
+    pub fn heading(&mut self, heading: impl Display) {
+        self.line(format_args!("-- {heading}"));
+    }
+
+    /// Checks whether the limit is reached, for steps that are slow to
+    /// explain. A full trace still counts the lines it skips.
+    pub fn is_full(&self) -> bool {
+        self.lines.len() >= self.limit
+    }
+
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+
+    /// Gets how many lines didn't fit.
+    pub fn skipped(&self) -> usize {
+        self.skipped
+    }
+}
+
+impl Display for Trace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for line in &self.lines {
+            writeln!(f, "{line}")?;
+        }
+        match self.skipped {
+            0 => Ok(()),
+            skipped => writeln!(f, "... {skipped} more line(s)"),
+        }
+    }
+}
+
+/// Reads `--explain` and `--explain-limit=<lines>` from a day binary's flags:
+/// how many lines to explain with, or `None` without `--explain`.
+pub fn limit_from_flags(flags: &[String]) -> Result<Option<usize>, String> {
+    let mut limit = DEFAULT_LIMIT;
+    for flag in flags {
+        if let Some(lines) = flag.strip_prefix("--explain-limit=") {
+            limit = lines
+                .parse()
+                .map_err(|_| format!("`{lines}` isn't a number of lines"))?;
+        }
+    }
+    Ok(flags
+        .iter()
+        .any(|flag| flag == "--explain")
+        .then_some(limit))
+}
+
+/// Prints how a day found its answers if its flags ask for it, for a day's
+/// `main` to call after the answers. Prints the error and exits if the limit
+/// isn't a number.
+///
+/// * `input`: the parsed puzzle input
+/// * `flags`: the flags `source::read_or_exit` handed back
+pub fn print_if_asked<S: Solution>(input: &S::Input, flags: &[String]) {
+    let limit = limit_from_flags(flags).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        process::exit(1);
+    });
+    let Some(limit) = limit else {
+        return;
+    };
+
+    let mut trace = Trace::new(limit);
+    S::explain(input, &mut trace);
+    match trace.lines().is_empty() {
+        true => println!("\nnothing to explain"),
+        false => print!("\n{trace}"),
+    }
+}
@@ -0,0 +1,58 @@
+use std::{env, fs};
+
+use aoc::{
+    answers::{input_hash, Answers, Check},
+    submit::{History, Verdict},
+};
+
+#[test]
+fn confirmed_answers_are_checked_per_input() {
+    let path = env::temp_dir().join(format!("aoc-answers-{}.txt", std::process::id()));
+    let _ = fs::remove_file(&path);
+
+    let input = "1 2 3\n";
+    let other = "4 5 6\n";
+    assert_ne!(input_hash(input), input_hash(other));
+
+    let mut answers = Answers::load(&path).unwrap();
+    assert_eq!(answers.check(5, 1, input_hash(input), "6"), Check::Unknown);
+    answers.confirm(5, 1, input_hash(input), "6").unwrap();
+    answers.confirm(5, 1, input_hash(input), "7").unwrap();
+
+    let answers = Answers::load(&path).unwrap();
+    assert_eq!(answers.answers().len(), 1);
+    assert_eq!(answers.check(5, 1, input_hash(input), "7"), Check::Matches);
+    assert_eq!(
+        answers.check(5, 1, input_hash(input), "6"),
+        Check::Differs("7".to_string())
+    );
+    // a different input has its own answers
+    assert_eq!(answers.check(5, 1, input_hash(other), "7"), Check::Unknown);
+    assert_eq!(answers.check(5, 2, input_hash(input), "7"), Check::Unknown);
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn only_accepted_answers_are_confirmed_from_the_history() {
+    let dir = env::temp_dir().join(format!("aoc-accepted-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+
+    let mut history = History::load(dir.join("day05.history")).unwrap();
+    history.record(1, "5", &Verdict::TooLow).unwrap();
+    history.record(1, "6", &Verdict::Correct).unwrap();
+    history.record(2, "100", &Verdict::Wrong).unwrap();
+
+    let hash = input_hash("1 2 3\n");
+    let mut answers = Answers::load(dir.join("answers.txt")).unwrap();
+    assert_eq!(
+        answers.confirm_accepted(5, hash, &history).unwrap(),
+        [(1, "6".to_string())]
+    );
+
+    let answers = Answers::load(dir.join("answers.txt")).unwrap();
+    assert_eq!(answers.get(5, 1, hash), Some("6"));
+    assert_eq!(answers.get(5, 2, hash), None);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
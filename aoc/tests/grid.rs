@@ -0,0 +1,39 @@
+use aoc::grid::{Direction, Grid};
+
+#[test]
+fn reads_rectangles_of_ascii() {
+    let grid = Grid::parse("467.\n..*.\n").unwrap();
+
+    assert_eq!((grid.height(), grid.width()), (2, 4));
+    assert_eq!(grid.get((1, 2)), Some('*'));
+    assert_eq!(grid.get((2, 0)), None);
+    assert_eq!(grid.find('*'), Some((1, 2)));
+
+    assert!(Grid::parse("467.\n..*\n").is_err());
+    assert!(Grid::parse("4ĵ").is_err());
+}
+
+#[test]
+fn steps_stay_in_the_grid() {
+    let grid = Grid::parse("F7\nLJ").unwrap();
+
+    assert_eq!(grid.step((0, 0), Direction::East), Some((0, 1)));
+    assert_eq!(grid.step((0, 0), Direction::North), None);
+    assert_eq!(grid.step((0, 1), Direction::East), None);
+    assert_eq!(
+        grid.neighbours((1, 0)).collect::<Vec<_>>(),
+        [(Direction::North, (0, 0)), (Direction::East, (1, 1))]
+    );
+}
+
+#[test]
+fn around_includes_diagonals() {
+    let grid = Grid::parse("....\n.12.\n....").unwrap();
+
+    assert_eq!(grid.around(1, 1..=2).count(), 12);
+    // clipped at the edges
+    assert_eq!(
+        grid.around(0, 0..=0).collect::<Vec<_>>(),
+        [(0, 0), (0, 1), (1, 0), (1, 1)]
+    );
+}
@@ -0,0 +1,82 @@
+use std::{
+    fs,
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use aoc::input::{InputError, InputManager, UreqBackend};
+use common::{temp_cache, MockServer, Request};
+
+mod common;
+
+fn manager(cache: &PathBuf, server: &MockServer) -> InputManager<UreqBackend> {
+    InputManager::new(cache, UreqBackend::new())
+        .with_base_url(&server.url)
+        .with_session("secret")
+        .with_rate_limit(Duration::ZERO)
+}
+
+#[test]
+fn fetches_once_then_uses_cache() {
+    let server = MockServer::start(200, "1 2 3\n");
+    let cache = temp_cache();
+
+    let input = manager(&cache, &server).get(2023, 5).unwrap();
+    assert_eq!(input, "1 2 3\n");
+    assert_eq!(
+        server.requests(),
+        vec![Request {
+            line: "GET /2023/day/5/input HTTP/1.1".to_string(),
+            cookie: "session=secret".to_string(),
+            body: String::new(),
+        }]
+    );
+    assert_eq!(
+        fs::read_to_string(cache.join("2023/day05.txt")).unwrap(),
+        input
+    );
+
+    // a new manager (e.g. the next run) still shouldn't fetch it again
+    let again = manager(&cache, &server).get(2023, 5).unwrap();
+    assert_eq!(again, input);
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn errors_are_not_cached() {
+    let server = MockServer::start(404, "Not found");
+    let cache = temp_cache();
+    let manager = manager(&cache, &server);
+
+    match manager.get(2023, 25) {
+        Err(InputError::Status { status: 404, .. }) => {}
+        other => panic!("expected a 404, got {other:?}"),
+    }
+    assert!(manager.cached(2023, 25).is_none());
+}
+
+#[test]
+fn missing_session_never_sends_a_request() {
+    let server = MockServer::start(200, "input");
+    let cache = temp_cache();
+    let manager = InputManager::new(&cache, UreqBackend::new())
+        .with_base_url(&server.url)
+        .with_rate_limit(Duration::ZERO);
+
+    assert!(matches!(manager.get(2023, 1), Err(InputError::NoSession)));
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn requests_are_rate_limited() {
+    let server = MockServer::start(200, "input");
+    let cache = temp_cache();
+    let manager = manager(&cache, &server).with_rate_limit(Duration::from_millis(300));
+
+    let start = Instant::now();
+    manager.get(2023, 1).unwrap();
+    manager.get(2023, 2).unwrap();
+
+    assert!(start.elapsed() >= Duration::from_millis(300));
+    assert_eq!(server.requests().len(), 2);
+}
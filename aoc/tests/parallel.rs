@@ -0,0 +1,16 @@
+use std::thread;
+
+use aoc::parallel;
+
+#[test]
+fn sequential_only_applies_inside_it_on_this_thread() {
+    assert!(parallel::enabled());
+    parallel::sequential(|| {
+        assert!(!parallel::enabled());
+        parallel::sequential(|| assert!(!parallel::enabled()));
+        // still off once the inner one is done
+        assert!(!parallel::enabled());
+        assert!(thread::spawn(parallel::enabled).join().unwrap());
+    });
+    assert!(parallel::enabled());
+}
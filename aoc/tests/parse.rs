@@ -0,0 +1,154 @@
+use aoc::parse::{
+    self, alt, char_if, delimited, integer, key_value, labelled, line, lines, list, many, map,
+    pair, section, sections, separated, spanned, tag, take_while, try_map, unsigned, word,
+    ErrorKind, Found,
+};
+
+#[test]
+fn parses_integers() {
+    assert_eq!(parse::run(integer::<i64>(), "-42"), Ok(-42));
+    assert_eq!(parse::run(unsigned::<u8>(), "255"), Ok(255));
+
+    let err = parse::run(unsigned::<u8>(), "256").unwrap_err();
+    assert_eq!(err.kind, ErrorKind::NumberTooLarge);
+    assert_eq!((err.span.start, err.span.end), (0, 3));
+
+    // a sign is only read by `integer`, and only for signed types
+    let err = parse::run(unsigned::<u32>(), "-1").unwrap_err();
+    assert_eq!(err.kind, ErrorKind::Expected("a number"));
+    let err = parse::run(integer::<u32>(), "-1").unwrap_err();
+    assert_eq!(err.kind, ErrorKind::Expected("a number without a sign"));
+}
+
+#[test]
+fn lists_skip_any_spaces() {
+    let numbers = || list(integer::<i32>());
+
+    assert_eq!(
+        parse::run(numbers(), "  41 48\t 83  "),
+        Ok(vec![41, 48, 83])
+    );
+    assert_eq!(parse::run(numbers(), ""), Ok(vec![]));
+
+    let err = parse::run(numbers(), "41 4x8").unwrap_err();
+    assert_eq!(err.kind, ErrorKind::Unexpected);
+    assert_eq!(err.found, Found::Text("x8".to_string()));
+    assert_eq!(err.span.column, 5);
+}
+
+#[test]
+fn errors_point_at_the_line_and_column() {
+    let races = lines(labelled("Time:", list(integer::<u64>())));
+
+    let err = parse::run(races, "Time: 7 15\nTime: 30 x\n").unwrap_err();
+    assert_eq!((err.span.line, err.span.column), (2, 10));
+    assert_eq!(
+        err.to_string(),
+        "line 2, column 10: unexpected input, found `x`"
+    );
+
+    let err = parse::run(labelled("Time:", integer::<u64>()), "Distance: 9").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "line 1, column 1: expected `Time:`, found `Distance:`"
+    );
+}
+
+#[test]
+fn spans_are_in_the_whole_input() {
+    let dots = || take_while(|c| c == '.');
+    let numbers = lines(many(delimited(dots(), spanned(unsigned::<u32>()), dots())));
+    let source = "467..114\r\n..35.";
+
+    let numbers = parse::run(numbers, source).unwrap();
+    let spans: Vec<_> = numbers
+        .iter()
+        .flatten()
+        .map(|number| (number.value, number.span.line, number.span.column))
+        .collect();
+    assert_eq!(spans, vec![(467, 1, 1), (114, 1, 6), (35, 2, 3)]);
+    assert_eq!(numbers[1][0].span.text(source), "35");
+}
+
+#[test]
+fn key_values_and_alternatives() {
+    let node = || {
+        key_value(
+            word(),
+            "=",
+            delimited(tag("("), separated(word(), tag(", ")), tag(")")),
+        )
+    };
+    assert_eq!(
+        parse::run(node(), "AAA = (BBB, CCC)"),
+        Ok(("AAA", vec!["BBB", "CCC"]))
+    );
+    let err = parse::run(node(), "AAA = (BBB CCC)").unwrap_err();
+    assert_eq!(err.kind, ErrorKind::Tag(")"));
+
+    let direction = alt(map(tag("L"), |_| 'L'), map(tag("R"), |_| 'R'));
+    assert_eq!(
+        parse::run(many(direction), "LRRL"),
+        Ok(vec!['L', 'R', 'R', 'L'])
+    );
+}
+
+#[test]
+fn sections_end_at_blank_lines() {
+    let almanac = || {
+        parse::pair(
+            section(line(labelled("seeds:", list(unsigned::<u64>())))),
+            sections(parse::preceded(
+                line(word()),
+                lines(list(unsigned::<u64>())),
+            )),
+        )
+    };
+    let source = "seeds: 79 14\n\nsoil\n50 98 2\n52 50 48\n\n\nwater\n0 15 37\n";
+
+    let (seeds, maps) = parse::run(almanac(), source).unwrap();
+    assert_eq!(seeds, vec![79, 14]);
+    assert_eq!(
+        maps,
+        vec![
+            vec![vec![50, 98, 2], vec![52, 50, 48]],
+            vec![vec![0, 15, 37]]
+        ]
+    );
+
+    let err = parse::run(almanac(), "seeds: 79\n\nsoil\n50 98 x\n").unwrap_err();
+    assert_eq!((err.span.line, err.span.column), (4, 7));
+}
+
+#[test]
+fn checked_values_fail_where_they_were_read() {
+    let even = try_map(unsigned::<u32>(), |number| match number % 2 {
+        0 => Ok(number),
+        _ => Err(format!("{number} is odd")),
+    });
+
+    let err = parse::run(list(even), "2 4 7 8").unwrap_err();
+    assert_eq!(err.to_string(), "line 1, column 5: 7 is odd");
+}
+
+#[test]
+fn single_characters() {
+    let card = || {
+        pair(
+            char_if("a rank", |c| c.is_ascii_digit()),
+            char_if("a suit", char::is_lowercase),
+        )
+    };
+
+    assert_eq!(
+        parse::run(many(card()), "2h9s"),
+        Ok(vec![('2', 'h'), ('9', 's')])
+    );
+
+    let err = parse::run(many(card()), "2h9S").unwrap_err();
+    assert_eq!(err.kind, ErrorKind::Expected("a suit"));
+    assert_eq!(err.span.column, 4);
+
+    let err = parse::run(card(), "2").unwrap_err();
+    assert_eq!(err.found, Found::EndOfInput);
+}
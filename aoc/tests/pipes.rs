@@ -0,0 +1,33 @@
+use aoc::{
+    grid::{Direction, Grid},
+    pipes::{self, WalkError},
+};
+
+#[test]
+fn pipes_connect_two_directions() {
+    assert_eq!(
+        pipes::connections('L'),
+        Some([Direction::North, Direction::East])
+    );
+    assert_eq!(pipes::connections('S'), None);
+    assert_eq!(pipes::pipe_between(Direction::South, Direction::East), 'F');
+}
+
+#[test]
+fn loops_are_followed_back_to_the_start() {
+    let grid = Grid::parse("F-7\n|.|\nL-J").unwrap();
+    let path = pipes::walk_loop((0, 0), Direction::East, |pos| grid.get(pos)).unwrap();
+    assert_eq!(path.len(), 8);
+    assert_eq!(path[..3], [(0, 0), (0, 1), (0, 2)]);
+
+    let broken = Grid::parse("F-7\n|.-\nL-J").unwrap();
+    assert_eq!(
+        pipes::walk_loop((0, 0), Direction::East, |pos| broken.get(pos)),
+        Err(WalkError::Breaks((1, 2)))
+    );
+    let open = Grid::parse("F--\n|..\nL--").unwrap();
+    assert_eq!(
+        pipes::walk_loop((0, 0), Direction::East, |pos| open.get(pos)),
+        Err(WalkError::LeavesGrid((0, 2)))
+    );
+}
@@ -0,0 +1,161 @@
+use std::{env, fs, path::PathBuf};
+
+use aoc::source::{self, InputSource, SourceError};
+
+/// Gets an empty directory for one test.
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("aoc-source-{name}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+fn args(args: &[&str]) -> Vec<String> {
+    args.iter().map(|arg| arg.to_string()).collect()
+}
+
+#[test]
+fn picks_the_source_from_the_arguments() {
+    let cases = [
+        (&[][..], InputSource::Default),
+        (
+            &["input.txt"],
+            InputSource::Path(PathBuf::from("input.txt")),
+        ),
+        (&["-"], InputSource::Stdin),
+        (
+            &["--input-str", "1 2\n3"],
+            InputSource::Inline("1 2\n3".to_string()),
+        ),
+    ];
+    for (given, expected) in cases {
+        let (source, rest) = InputSource::from_args(args(given), &[]).unwrap();
+        assert_eq!(source, expected, "{given:?}");
+        assert!(rest.is_empty(), "{given:?}");
+    }
+}
+
+#[test]
+fn other_flags_are_handed_back_in_order() {
+    let (source, rest) = InputSource::from_args(
+        args(&["--check", "test.txt", "--table"]),
+        &["--table", "--check"],
+    )
+    .unwrap();
+    assert_eq!(source, InputSource::Path(PathBuf::from("test.txt")));
+    assert_eq!(rest, ["--check", "--table"]);
+
+    // the input after --input-str is the input even if it looks like a flag
+    let (source, rest) = InputSource::from_args(args(&["--input-str", "--check"]), &[]).unwrap();
+    assert_eq!(source, InputSource::Inline("--check".to_string()));
+    assert!(rest.is_empty());
+}
+
+#[test]
+fn only_one_input_can_be_given() {
+    let cases = [
+        &["a.txt", "b.txt"][..],
+        &["a.txt", "-"],
+        &["-", "--input-str", "1"],
+        &["--input-str", "1", "a.txt"],
+    ];
+    for given in cases {
+        let err = InputSource::from_args(args(given), &[]).unwrap_err();
+        assert!(matches!(err, SourceError::Usage(_)), "{given:?}");
+        assert_eq!(
+            err.to_string(),
+            "only one input can be given (a path, `-` or --input-str)"
+        );
+    }
+
+    let err = InputSource::from_args(args(&["--input-str"]), &[]).unwrap_err();
+    assert_eq!(err.to_string(), "--input-str needs the input after it");
+}
+
+#[test]
+fn flags_can_take_values() {
+    let flags = ["--html=", "--render", "--render=plain"];
+    let cases = [
+        (&["--html=a.html"][..], "--html=a.html"),
+        (&["--html", "a.html"], "--html=a.html"),
+        (&["--render"], "--render"),
+        (&["--render=plain"], "--render=plain"),
+    ];
+    for (given, expected) in cases {
+        let (source, rest) = InputSource::from_args(args(given), &flags).unwrap();
+        assert_eq!(source, InputSource::Default, "{given:?}");
+        assert_eq!(rest, [expected], "{given:?}");
+    }
+}
+
+#[test]
+fn unknown_flags_are_errors() {
+    let flags = ["--check", "--html="];
+    let cases = [
+        (&["--chek"][..], "unknown flag `--chek`"),
+        (&["--check=yes"], "unknown flag `--check=yes`"),
+        (&["--html"], "--html needs a value after it"),
+        (&["--html="], "--html needs a value after it"),
+        (&["input.txt", "--explain"], "unknown flag `--explain`"),
+    ];
+    for (given, expected) in cases {
+        let err = InputSource::from_args(args(given), &flags).unwrap_err();
+        assert!(matches!(err, SourceError::Usage(_)), "{given:?}");
+        assert_eq!(err.to_string(), expected, "{given:?}");
+    }
+
+    assert_eq!(
+        source::usage(3, &flags),
+        "usage: day3 [path | - | --input-str <input>] [--check] [--html=<value>]"
+    );
+}
+
+#[test]
+fn reads_paths_and_inline_inputs() {
+    let dir = temp_dir("paths");
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("input.txt");
+    fs::write(&path, "from a file\n").unwrap();
+
+    let source = InputSource::Path(path.clone());
+    assert_eq!(source.read(5, &dir).unwrap(), "from a file\n");
+    let source = InputSource::Inline("inline".to_string());
+    assert_eq!(source.read(5, &dir).unwrap(), "inline");
+
+    let missing = InputSource::Path(dir.join("missing.txt"));
+    let err = missing.read(5, &dir).unwrap_err();
+    assert!(matches!(err, SourceError::Read { .. }), "{err}");
+    assert!(err.to_string().starts_with("couldn't read input file "));
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+// the only test that sets AOC_CACHE_DIR, so the others can't see it change
+#[test]
+fn the_default_falls_back_to_the_cache() {
+    let root = temp_dir("default");
+    let dir = root.join("day5/rust_sol");
+    let cache = root.join("inputs");
+    fs::create_dir_all(&dir).unwrap();
+    env::set_var("AOC_CACHE_DIR", &cache);
+
+    // nothing local or cached
+    let err = InputSource::Default.read(5, &dir).unwrap_err();
+    assert!(matches!(err, SourceError::Missing { day: 5, .. }), "{err}");
+    assert!(err
+        .to_string()
+        .starts_with("no input for day 5: save it as "));
+
+    fs::create_dir_all(cache.join("2023")).unwrap();
+    fs::write(cache.join("2023/day05.txt"), "cached\n").unwrap();
+    assert_eq!(InputSource::Default.read(5, &dir).unwrap(), "cached\n");
+    assert_eq!(source::read_default(5, &dir).unwrap(), "cached\n");
+
+    // an empty input.txt is a placeholder, a filled in one wins
+    fs::write(dir.join("input.txt"), "").unwrap();
+    assert_eq!(InputSource::Default.read(5, &dir).unwrap(), "cached\n");
+    fs::write(dir.join("input.txt"), "local\n").unwrap();
+    assert_eq!(InputSource::Default.read(5, &dir).unwrap(), "local\n");
+
+    env::remove_var("AOC_CACHE_DIR");
+    fs::remove_dir_all(&root).unwrap();
+}
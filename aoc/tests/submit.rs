@@ -0,0 +1,113 @@
+use std::{path::PathBuf, time::Duration};
+
+use aoc::{
+    input::{InputManager, UreqBackend},
+    submit::{self, History, Refusal, SubmitError, Verdict},
+};
+use common::{temp_cache, MockServer};
+
+mod common;
+
+const CORRECT: &str =
+    "<article><p>That's the right answer! You are one gold star closer.</p></article>";
+const TOO_HIGH: &str =
+    "<article><p>That's not the right answer; your answer is too high.</p></article>";
+const WAIT: &str = "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.</p></article>";
+
+fn manager(cache: &PathBuf, server: &MockServer) -> InputManager<UreqBackend> {
+    InputManager::new(cache, UreqBackend::new())
+        .with_base_url(&server.url)
+        .with_session("secret")
+        .with_rate_limit(Duration::ZERO)
+}
+
+#[test]
+fn reads_verdicts() {
+    assert_eq!(Verdict::from_response(CORRECT), Verdict::Correct);
+    assert_eq!(Verdict::from_response(TOO_HIGH), Verdict::TooHigh);
+    assert_eq!(
+        Verdict::from_response("That's not the right answer; your answer is too low."),
+        Verdict::TooLow
+    );
+    assert_eq!(
+        Verdict::from_response("That's not the right answer. If you're stuck..."),
+        Verdict::Wrong
+    );
+    assert_eq!(
+        Verdict::from_response(WAIT),
+        Verdict::Wait(Some(Duration::from_secs(65)))
+    );
+    assert_eq!(
+        Verdict::from_response(
+            "You don't seem to be solving the right level.  Did you already complete it?"
+        ),
+        Verdict::AlreadySolved
+    );
+}
+
+#[test]
+fn posts_answer_and_records_verdict() {
+    let server = MockServer::start(200, TOO_HIGH);
+    let cache = temp_cache();
+    let manager = manager(&cache, &server);
+
+    let verdict = submit::submit(&manager, 2023, 7, 2, "1000").unwrap();
+    assert_eq!(verdict, Verdict::TooHigh);
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].line, "POST /2023/day/7/answer HTTP/1.1");
+    assert_eq!(requests[0].cookie, "session=secret");
+    assert_eq!(requests[0].body, "level=2&answer=1000");
+
+    let history = History::load(submit::history_path(&manager, 2023, 7)).unwrap();
+    assert_eq!(history.attempts().len(), 1);
+    assert_eq!(history.bounds(2), (None, Some(1000)));
+}
+
+#[test]
+fn refuses_known_wrong_and_out_of_bounds_answers() {
+    let server = MockServer::start(200, TOO_HIGH);
+    let cache = temp_cache();
+    let manager = manager(&cache, &server);
+    submit::submit(&manager, 2023, 7, 1, "500").unwrap();
+
+    for (answer, refusal) in [
+        ("500", Refusal::KnownWrong(Verdict::TooHigh)),
+        ("501", Refusal::AboveBound("500".to_string())),
+    ] {
+        match submit::submit(&manager, 2023, 7, 1, answer) {
+            Err(SubmitError::Refused(refused)) => assert_eq!(refused, refusal),
+            other => panic!("expected {answer} to be refused, got {other:?}"),
+        }
+    }
+    // part two has its own bounds
+    submit::submit(&manager, 2023, 7, 2, "501").unwrap();
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn refuses_solved_parts_and_skips_waits() {
+    let cache = temp_cache();
+
+    let waiting = MockServer::start(200, WAIT);
+    let manager = manager(&cache, &waiting);
+    let verdict = submit::submit(&manager, 2023, 1, 1, "42").unwrap();
+    assert!(matches!(verdict, Verdict::Wait(_)));
+    // having to wait says nothing about the answer, so it can be sent again
+    assert!(History::load(submit::history_path(&manager, 2023, 1))
+        .unwrap()
+        .attempts()
+        .is_empty());
+
+    let correct = MockServer::start(200, CORRECT);
+    let manager = self::manager(&cache, &correct);
+    assert_eq!(
+        submit::submit(&manager, 2023, 1, 1, "42").unwrap(),
+        Verdict::Correct
+    );
+    match submit::submit(&manager, 2023, 1, 1, "43") {
+        Err(SubmitError::Refused(Refusal::AlreadySolved(answer))) => assert_eq!(answer, "42"),
+        other => panic!("expected a solved part to be refused, got {other:?}"),
+    }
+}
@@ -0,0 +1,43 @@
+use aoc::trace::{self, Trace};
+
+#[test]
+fn lines_past_the_limit_are_counted() {
+    let mut trace = Trace::new(2);
+    trace.heading("part one");
+    for step in 1..=4 {
+        trace.line(format_args!("step {step}"));
+    }
+
+    assert!(trace.is_full());
+    assert_eq!(trace.lines(), ["-- part one", "step 1"]);
+    assert_eq!(trace.skipped(), 3);
+    assert_eq!(
+        trace.to_string(),
+        "-- part one\nstep 1\n... 3 more line(s)\n"
+    );
+}
+
+#[test]
+fn the_limit_is_read_from_the_flags() {
+    let flags = |flags: &[&str]| {
+        flags
+            .iter()
+            .map(|flag| flag.to_string())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(trace::limit_from_flags(&flags(&["--check"])), Ok(None));
+    assert_eq!(
+        trace::limit_from_flags(&flags(&["--explain"])),
+        Ok(Some(trace::DEFAULT_LIMIT))
+    );
+    assert_eq!(
+        trace::limit_from_flags(&flags(&["--explain-limit=20", "--explain"])),
+        Ok(Some(20))
+    );
+    // a limit without --explain is still checked
+    assert_eq!(
+        trace::limit_from_flags(&flags(&["--explain-limit=lots"])),
+        Err("`lots` isn't a number of lines".to_string())
+    );
+}
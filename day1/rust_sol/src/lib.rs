@@ -0,0 +1,149 @@
+use aoc::{
+    parse::{self, lines, map, rest},
+    trace::Trace,
+    ParseError, Solution,
+};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Trebuchet?!: every line hides a calibration value made of its first and
+/// last digit.
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<String>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(parse_lines(input)?)
+    }
+
+    fn part_one(lines: &Self::Input) -> u32 {
+        sum_calibration_values(lines, false)
+    }
+
+    fn part_two(lines: &Self::Input) -> u32 {
+        sum_calibration_values(lines, true)
+    }
+
+    fn explain(lines: &Self::Input, trace: &mut Trace) {
+        for (number, line) in lines.iter().enumerate() {
+            trace.line(format_args!(
+                "line {}: `{line}`: {}, with words {}",
+                number + 1,
+                explain_digits(line, false),
+                explain_digits(line, true)
+            ));
+        }
+    }
+}
+
+/// Describes the digits decoded from a line, e.g. `2 and 9 = 29`.
+fn explain_digits(line: &str, include_words: bool) -> String {
+    let first = get_first_digit(line, include_words);
+    let last = get_last_digit(line, include_words);
+    match first.zip(last) {
+        Some((first, last)) => format!("{first} and {last} = {}", 10 * first + last),
+        None => "no digits".to_string(),
+    }
+}
+
+/// Splits the calibration document into its lines.
+pub fn parse_lines(contents: &str) -> Result<Vec<String>, parse::Error> {
+    parse::run(lines(map(rest(), String::from)), contents)
+}
+
+/// Adds up the calibration value of every line. Lines without a digit don't
+/// count.
+///
+/// * `lines`: the lines of the calibration document
+/// * `include_words`: whether spelled out digits (e.g. "two") count
+pub fn sum_calibration_values(lines: &[String], include_words: bool) -> u32 {
+    let value = |line: &String| calibration_value(line, include_words);
+
+    #[cfg(feature = "parallel")]
+    if aoc::parallel::enabled() {
+        return lines.par_iter().filter_map(value).sum();
+    }
+    lines.iter().filter_map(value).sum()
+}
+
+/// Gets the two digit number made of a line's first and last digit, e.g. 38
+/// for "pqr3stu8vwx".
+///
+/// * `line`: a line of the calibration document
+/// * `include_words`: whether spelled out digits (e.g. "two") count
+pub fn calibration_value(line: &str, include_words: bool) -> Option<u32> {
+    Some(10 * get_first_digit(line, include_words)? + get_last_digit(line, include_words)?)
+}
+
+/// Finds the first digit in a line, or `None` if it doesn't have one.
+///
+/// * `line`: the line to search
+/// * `include_words`: whether spelled out digits (e.g. "two") count
+pub fn get_first_digit(line: &str, include_words: bool) -> Option<u32> {
+    for (index, char) in line.char_indices() {
+        if include_words {
+            if let Some(spelled_num) = find_spelled_digit(&line[..index]) {
+                return Some(spelled_num);
+            }
+        }
+        if let Some(num) = char.to_digit(10) {
+            return Some(num);
+        }
+    }
+    // a spelled out digit may end the line
+    match include_words {
+        true => find_spelled_digit(line),
+        false => None,
+    }
+}
+
+/// Finds the last digit in a line, or `None` if it doesn't have one.
+///
+/// * `line`: the line to search
+/// * `include_words`: whether spelled out digits (e.g. "two") count
+pub fn get_last_digit(line: &str, include_words: bool) -> Option<u32> {
+    for (index, char) in line.char_indices().rev() {
+        if include_words {
+            if let Some(spelled_num) = find_spelled_digit(&line[index + char.len_utf8()..]) {
+                return Some(spelled_num);
+            }
+        }
+        if let Some(num) = char.to_digit(10) {
+            return Some(num);
+        }
+    }
+    match include_words {
+        true => find_spelled_digit(line),
+        false => None,
+    }
+}
+
+/// Finds a spelled out digit in a substring. Only one to nine count, the
+/// puzzle never spells out zero.
+fn find_spelled_digit(substring: &str) -> Option<u32> {
+    if substring.contains("one") {
+        return Some(1);
+    } else if substring.contains("two") {
+        return Some(2);
+    } else if substring.contains("three") {
+        return Some(3);
+    } else if substring.contains("four") {
+        return Some(4);
+    } else if substring.contains("five") {
+        return Some(5);
+    } else if substring.contains("six") {
+        return Some(6);
+    } else if substring.contains("seven") {
+        return Some(7);
+    } else if substring.contains("eight") {
+        return Some(8);
+    } else if substring.contains("nine") {
+        return Some(9);
+    }
+    None
+}
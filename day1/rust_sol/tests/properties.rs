@@ -0,0 +1,54 @@
+use aoc::{
+    generate::{self, Rng},
+    Solution,
+};
+use day1::Day1;
+
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// Finds every digit in a line in order, by checking what starts at each
+/// position.
+fn digits(line: &str, include_words: bool) -> Vec<u32> {
+    (0..line.len())
+        .filter_map(|index| {
+            let rest = &line[index..];
+            let digit = rest.chars().next()?.to_digit(10);
+            let word = WORDS
+                .iter()
+                .position(|word| include_words && rest.starts_with(word))
+                .map(|position| position as u32 + 1);
+            digit.or(word)
+        })
+        .collect()
+}
+
+fn reference(input: &str, include_words: bool) -> u32 {
+    input
+        .lines()
+        .map(|line| {
+            let digits = digits(line, include_words);
+            10 * digits[0] + digits[digits.len() - 1]
+        })
+        .sum()
+}
+
+#[test]
+fn matches_reference_on_random_inputs() {
+    for seed in 0..500 {
+        let input = generate::calibration(&mut Rng::new(seed), 20, 12);
+        let lines = Day1::parse(&input).unwrap();
+
+        assert_eq!(
+            Day1::part_one(&lines),
+            reference(&input, false),
+            "seed {seed}:\n{input}"
+        );
+        assert_eq!(
+            Day1::part_two(&lines),
+            reference(&input, true),
+            "seed {seed}:\n{input}"
+        );
+    }
+}
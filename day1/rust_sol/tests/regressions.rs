@@ -0,0 +1,18 @@
+use aoc::Solution;
+use day1::Day1;
+
+// lines were sliced at character counts, which aren't byte offsets (or char
+// boundaries) once a line has a multi-byte character before the digits
+#[test]
+fn non_ascii_lines() {
+    let lines = Day1::parse("é1two\nthreeü4\n").unwrap();
+    assert_eq!(Day1::part_one(&lines), 55);
+    assert_eq!(Day1::part_two(&lines), 46);
+}
+
+// "zero" was read as a digit, but only one to nine are spelled out
+#[test]
+fn zero_is_not_a_digit() {
+    let lines = Day1::parse("zero1nine\n4zero\n").unwrap();
+    assert_eq!(Day1::part_two(&lines), 19 + 44);
+}
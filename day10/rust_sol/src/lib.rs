@@ -0,0 +1,237 @@
+use aoc::{
+    grid::{Direction, Grid, Pos},
+    pipes::{self, WalkError},
+    trace::Trace,
+    ParseError, Solution,
+};
+
+pub use aoc::pipes::{connections, pipe_between};
+
+/// The pipe grid and the main loop through `S`.
+pub struct Maze {
+    grid: Grid,
+    start: Pos,
+    start_pipe: char,
+    path: Vec<Pos>,
+}
+
+impl Maze {
+    pub fn grid(&self) -> &Grid {
+        &self.grid
+    }
+
+    pub fn start(&self) -> Pos {
+        self.start
+    }
+
+    /// Gets the pipe under `S`, worked out from the pipes around it.
+    pub fn start_pipe(&self) -> char {
+        self.start_pipe
+    }
+
+    /// Gets the tiles of the main loop in order, starting at `S`.
+    pub fn path(&self) -> &[Pos] {
+        &self.path
+    }
+
+    /// Gets the character at `pos`, with `S` replaced by its pipe.
+    pub fn pipe(&self, pos: Pos) -> Option<char> {
+        match pos == self.start {
+            true => Some(self.start_pipe),
+            false => self.grid.get(pos),
+        }
+    }
+}
+
+/// Reads the grid and finds the main loop. There has to be exactly one `S`,
+/// connected to exactly two pipes, and the loop from it has to close.
+pub fn parse_maze(contents: &str) -> Result<Maze, ParseError> {
+    let grid = Grid::parse(contents)?;
+    let at = |(row, col): Pos| format!("line {}, column {}", row + 1, col + 1);
+
+    let mut start = None;
+    for (pos, char) in grid.cells() {
+        match char {
+            'S' if start.is_some() => return Err(format!("{}: a second `S`", at(pos)).into()),
+            'S' => start = Some(pos),
+            '.' => {}
+            char if connections(char).is_none() => {
+                return Err(format!("{}: `{char}` isn't a pipe", at(pos)).into())
+            }
+            _ => {}
+        }
+    }
+    let start = start.ok_or("there's no `S` in the maze")?;
+
+    let start_pipe = match start_connections(&grid, start)[..] {
+        [one, two] => pipe_between(one, two),
+        ref connected => {
+            return Err(format!(
+                "{}: `S` should connect to two pipes, not {}",
+                at(start),
+                connected.len()
+            )
+            .into())
+        }
+    };
+
+    // `S` only connects to pipes that connect back, so the walk never leaves
+    // the grid from it
+    let path = pipes::walk_loop(start, connections(start_pipe).unwrap()[0], |pos| {
+        grid.get(pos)
+    })
+    .map_err(|err| match err {
+        WalkError::LeavesGrid(pos) => format!("{}: the loop leaves the grid", at(pos)),
+        WalkError::Breaks(pos) => format!("{}: the loop breaks here", at(pos)),
+    })?;
+
+    Ok(Maze {
+        grid,
+        start,
+        start_pipe,
+        path,
+    })
+}
+
+/// Finds the directions from `S` to the pipes that connect back to it.
+fn start_connections(grid: &Grid, start: Pos) -> Vec<Direction> {
+    grid.neighbours(start)
+        .filter(|&(direction, pos)| {
+            grid.get(pos)
+                .and_then(connections)
+                .is_some_and(|ends| ends.contains(&direction.opposite()))
+        })
+        .map(|(direction, _)| direction)
+        .collect()
+}
+
+/// Finds the tile of the loop farthest from `S` (both ways round), and how
+/// many steps away it is.
+pub fn farthest(maze: &Maze) -> (Pos, usize) {
+    // the loop alternates between black and white squares of a chessboard, so
+    // its length is even and there's one farthest tile
+    let steps = maze.path.len() / 2;
+    (maze.path[steps], steps)
+}
+
+/// Finds the tiles the loop encloses by casting a ray from the left edge of
+/// each row: a tile is inside after crossing the loop an odd number of times.
+/// Only pipes going north count as crossings, so running along a `L--7` or a
+/// `F--7` crosses once or not at all.
+pub fn enclosed_tiles(maze: &Maze) -> Vec<Pos> {
+    let grid = &maze.grid;
+    let mut on_loop = vec![vec![false; grid.width()]; grid.height()];
+    for &(row, col) in &maze.path {
+        on_loop[row][col] = true;
+    }
+
+    let mut enclosed = vec![];
+    for (row, line) in on_loop.iter().enumerate() {
+        let mut inside = false;
+        for (col, &on_loop) in line.iter().enumerate() {
+            if !on_loop {
+                if inside {
+                    enclosed.push((row, col));
+                }
+                continue;
+            }
+            let goes_north = maze
+                .pipe((row, col))
+                .and_then(connections)
+                .is_some_and(|ends| ends.contains(&Direction::North));
+            if goes_north {
+                inside = !inside;
+            }
+        }
+    }
+    enclosed
+}
+
+/// Counts the tiles the loop encloses from its area, without looking at the
+/// tiles: the shoelace formula gives the area of the polygon through the
+/// loop's tiles, and Pick's theorem (area = inside + boundary / 2 - 1) turns
+/// that into the tiles inside.
+pub fn enclosed_by_area(maze: &Maze) -> usize {
+    let path = &maze.path;
+    let twice_area = path
+        .iter()
+        .zip(path.iter().cycle().skip(1))
+        .map(|(&(row, col), &(next_row, next_col))| {
+            col as i64 * next_row as i64 - next_col as i64 * row as i64
+        })
+        .sum::<i64>()
+        .abs();
+    // twice the area is even when the boundary is, since the loop is even
+    ((twice_area - path.len() as i64) / 2 + 1) as usize
+}
+
+/// Checks that ray casting and the shoelace formula find the same number of
+/// enclosed tiles.
+pub fn compare_methods(maze: &Maze) -> Result<(), String> {
+    let (rays, area) = (enclosed_tiles(maze).len(), enclosed_by_area(maze));
+    match rays == area {
+        true => Ok(()),
+        false => Err(format!(
+            "ray casting finds {rays} enclosed tiles but the shoelace formula finds {area}"
+        )),
+    }
+}
+
+/// Pipe Maze: the loop of pipes through `S`, how far around it the farthest
+/// tile is and how many tiles it encloses.
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Maze;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_maze(input)
+    }
+
+    fn part_one(maze: &Self::Input) -> usize {
+        farthest(maze).1
+    }
+
+    fn part_two(maze: &Self::Input) -> usize {
+        enclosed_tiles(maze).len()
+    }
+
+    fn explain(maze: &Self::Input, trace: &mut Trace) {
+        let (start_row, start_col) = maze.start;
+        let ((row, col), steps) = farthest(maze);
+
+        trace.heading("the loop");
+        trace.line(format_args!(
+            "`S` at line {}, column {} is a `{}`",
+            start_row + 1,
+            start_col + 1,
+            maze.start_pipe
+        ));
+        trace.line(format_args!(
+            "the loop is {} tiles long, the farthest is line {}, column {}, {steps} steps away",
+            maze.path.len(),
+            row + 1,
+            col + 1
+        ));
+
+        trace.heading("enclosed tiles");
+        let enclosed = enclosed_tiles(maze);
+        for rows in enclosed.chunk_by(|one, two| one.0 == two.0) {
+            let cols: Vec<String> = rows.iter().map(|(_, col)| (col + 1).to_string()).collect();
+            trace.line(format_args!(
+                "line {}: {} tile(s), at columns {}",
+                rows[0].0 + 1,
+                rows.len(),
+                cols.join(", ")
+            ));
+        }
+        trace.line(format_args!(
+            "the shoelace formula and Pick's theorem give {}",
+            enclosed_by_area(maze)
+        ));
+    }
+}
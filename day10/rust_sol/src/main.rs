@@ -0,0 +1,27 @@
+use aoc::{source, trace, Solution};
+use day10::Day10;
+use std::process;
+
+fn main() {
+    let (contents, flags) =
+        source::read_or_exit(Day10::DAY, env!("CARGO_MANIFEST_DIR"), &["--check"]);
+    let check = flags.iter().any(|flag| flag == "--check");
+    let maze = Day10::parse(&contents).unwrap_or_else(|err| {
+        eprintln!("couldn't parse input: {err}");
+        process::exit(1);
+    });
+
+    if check {
+        match day10::compare_methods(&maze) {
+            Ok(()) => println!("ray casting and the shoelace formula agree"),
+            Err(err) => {
+                eprintln!("{err}");
+                process::exit(1);
+            }
+        }
+    }
+
+    println!("Answer 1: {}", Day10::part_one(&maze));
+    println!("Answer 2: {}", Day10::part_two(&maze));
+    trace::print_if_asked::<Day10>(&maze, &flags);
+}
@@ -0,0 +1,62 @@
+use aoc::Solution;
+use day10::Day10;
+
+const EXAMPLE: &str = include_str!("../test.txt");
+// part two has its own example
+const EXAMPLE_TWO: &str = include_str!("../test2.txt");
+
+#[test]
+fn part_one_example() {
+    let maze = Day10::parse(EXAMPLE).unwrap();
+    assert_eq!(maze.start_pipe(), 'F');
+    assert_eq!(day10::farthest(&maze), ((2, 4), 8));
+    assert_eq!(Day10::part_one(&maze), 8);
+}
+
+#[test]
+fn part_two_example() {
+    let maze = Day10::parse(EXAMPLE_TWO).unwrap();
+    assert_eq!(maze.start_pipe(), '7');
+    assert_eq!(Day10::part_two(&maze), 10);
+    assert_eq!(day10::enclosed_by_area(&maze), 10);
+}
+
+#[test]
+fn squeezing_between_pipes_is_outside() {
+    let maze = Day10::parse(
+        "..........\n\
+         .S------7.\n\
+         .|F----7|.\n\
+         .||....||.\n\
+         .||....||.\n\
+         .|L-7F-J|.\n\
+         .|..||..|.\n\
+         .L--JL--J.\n\
+         ..........\n",
+    )
+    .unwrap();
+
+    assert_eq!(
+        day10::enclosed_tiles(&maze),
+        [(6, 2), (6, 3), (6, 6), (6, 7)]
+    );
+    assert_eq!(day10::compare_methods(&maze), Ok(()));
+}
+
+#[test]
+fn rejects_broken_loops() {
+    let inputs = [
+        // no S, or two
+        ".F7.\n.LJ.",
+        "SF7.\n.LS.",
+        // S connects to three pipes
+        ".|.\n-S-\n.|.",
+        // the loop runs into ground
+        "S7\n|.",
+        // and off the edge of the grid
+        "S-\n|.",
+    ];
+    for input in inputs {
+        assert!(Day10::parse(input).is_err(), "{input:?}");
+    }
+}
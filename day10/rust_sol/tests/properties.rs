@@ -0,0 +1,116 @@
+use std::collections::{HashMap, VecDeque};
+
+use aoc::{
+    generate::{self, Rng},
+    Solution,
+};
+use day10::Day10;
+
+/// Gets the (row, column) steps a tile's pipe connects, `S` connecting
+/// everywhere.
+fn ends(tile: u8) -> &'static [(isize, isize)] {
+    match tile {
+        b'|' => &[(-1, 0), (1, 0)],
+        b'-' => &[(0, -1), (0, 1)],
+        b'L' => &[(-1, 0), (0, 1)],
+        b'J' => &[(-1, 0), (0, -1)],
+        b'7' => &[(1, 0), (0, -1)],
+        b'F' => &[(1, 0), (0, 1)],
+        b'S' => &[(-1, 0), (1, 0), (0, -1), (0, 1)],
+        _ => &[],
+    }
+}
+
+/// Finds the loop with a breadth-first search from `S` along pipes that
+/// connect both ways, and the enclosed tiles by flooding the outside of a
+/// grid at twice the resolution, where the gaps between pipes are cells of
+/// their own.
+fn reference(input: &str) -> (usize, usize) {
+    let grid: Vec<&[u8]> = input.lines().map(str::as_bytes).collect();
+    let (height, width) = (grid.len() as isize, grid[0].len() as isize);
+    let tile = |(row, col): (isize, isize)| match (0..height).contains(&row)
+        && (0..width).contains(&col)
+    {
+        true => grid[row as usize][col as usize],
+        false => b'.',
+    };
+    let connected = |(row, col): (isize, isize)| {
+        ends(tile((row, col)))
+            .iter()
+            .map(move |(rows, cols)| (row + rows, col + cols))
+            .filter(move |&next| ends(tile(next)).contains(&(row - next.0, col - next.1)))
+    };
+
+    let start = (0..height)
+        .flat_map(|row| (0..width).map(move |col| (row, col)))
+        .find(|&pos| tile(pos) == b'S')
+        .unwrap();
+    let mut distance = HashMap::from([(start, 0)]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(pos) = queue.pop_front() {
+        for next in connected(pos) {
+            if !distance.contains_key(&next) {
+                distance.insert(next, distance[&pos] + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+    let farthest = *distance.values().max().unwrap();
+
+    // tile (row, col) is cell (2 row + 1, 2 col + 1), with a wall cell
+    // between two loop tiles that connect
+    let (big_height, big_width) = (2 * height + 1, 2 * width + 1);
+    let mut wall = vec![vec![false; big_width as usize]; big_height as usize];
+    for &(row, col) in distance.keys() {
+        wall[2 * row as usize + 1][2 * col as usize + 1] = true;
+        for (next_row, next_col) in connected((row, col)) {
+            wall[(row + next_row + 1) as usize][(col + next_col + 1) as usize] = true;
+        }
+    }
+    let mut outside = vec![vec![false; big_width as usize]; big_height as usize];
+    let mut stack = vec![(0isize, 0isize)];
+    while let Some((row, col)) = stack.pop() {
+        if !(0..big_height).contains(&row) || !(0..big_width).contains(&col) {
+            continue;
+        }
+        let (r, c) = (row as usize, col as usize);
+        if wall[r][c] || outside[r][c] {
+            continue;
+        }
+        outside[r][c] = true;
+        stack.extend([
+            (row - 1, col),
+            (row + 1, col),
+            (row, col - 1),
+            (row, col + 1),
+        ]);
+    }
+    let enclosed = (0..height)
+        .flat_map(|row| (0..width).map(move |col| (row, col)))
+        .filter(|&(row, col)| {
+            let (r, c) = (2 * row as usize + 1, 2 * col as usize + 1);
+            !wall[r][c] && !outside[r][c]
+        })
+        .count();
+
+    (farthest, enclosed)
+}
+
+#[test]
+fn matches_reference_on_random_inputs() {
+    for seed in 0..300 {
+        let mut rng = Rng::new(seed);
+        let (width, height) = (rng.range(2..=12) as usize, rng.range(2..=12) as usize);
+        let input = generate::pipe_maze(&mut rng, width, height, 40);
+        let maze = Day10::parse(&input).unwrap_or_else(|err| panic!("seed {seed}: {err}\n{input}"));
+        let (farthest, enclosed) = reference(&input);
+
+        assert_eq!(Day10::part_one(&maze), farthest, "seed {seed}:\n{input}");
+        assert_eq!(Day10::part_two(&maze), enclosed, "seed {seed}:\n{input}");
+        assert_eq!(
+            day10::compare_methods(&maze),
+            Ok(()),
+            "seed {seed}:\n{input}"
+        );
+    }
+}
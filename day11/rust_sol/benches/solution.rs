@@ -0,0 +1,9 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use day11::Day11;
+
+fn bench(c: &mut Criterion) {
+    aoc::bench::solution::<Day11>(c, env!("CARGO_MANIFEST_DIR"));
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
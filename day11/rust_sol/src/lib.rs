@@ -0,0 +1,177 @@
+use std::num::NonZeroU64;
+
+use aoc::{
+    grid::{Grid, Pos},
+    trace::Trace,
+    ParseError, Solution,
+};
+
+/// How many times as big the empty rows and columns get in part one.
+pub const PART_ONE: NonZeroU64 = NonZeroU64::new(2).unwrap();
+/// How many times as big the empty rows and columns get in part two.
+pub const PART_TWO: NonZeroU64 = NonZeroU64::new(1_000_000).unwrap();
+
+/// The galaxies in the image and which of its rows and columns are empty.
+pub struct Universe {
+    galaxies: Vec<Pos>,
+    /// how many empty rows come before each row
+    empty_rows: Vec<u64>,
+    /// how many empty columns come before each column
+    empty_cols: Vec<u64>,
+}
+
+impl Universe {
+    /// Gets the galaxies, in reading order.
+    pub fn galaxies(&self) -> &[Pos] {
+        &self.galaxies
+    }
+
+    /// Gets where a galaxy ends up once every empty row and column is
+    /// `factor` times as big.
+    pub fn expanded(&self, (row, col): Pos, factor: NonZeroU64) -> (u128, u128) {
+        let grown = factor.get() as u128 - 1;
+        (
+            row as u128 + grown * self.empty_rows[row] as u128,
+            col as u128 + grown * self.empty_cols[col] as u128,
+        )
+    }
+
+    /// Gets the empty rows and columns.
+    pub fn empty(&self) -> (Vec<usize>, Vec<usize>) {
+        let empty = |before: &[u64]| -> Vec<usize> {
+            (0..before.len() - 1)
+                .filter(|&i| before[i + 1] > before[i])
+                .collect()
+        };
+        (empty(&self.empty_rows), empty(&self.empty_cols))
+    }
+}
+
+/// Reads the image: a rectangle of `.` for space and `#` for galaxies.
+pub fn parse_universe(contents: &str) -> Result<Universe, ParseError> {
+    let grid = Grid::parse(contents)?;
+    if let Some(((row, col), char)) = grid.cells().find(|(_, char)| !matches!(char, '.' | '#')) {
+        return Err(format!(
+            "line {}, column {}: `{char}` is neither space nor a galaxy",
+            row + 1,
+            col + 1
+        )
+        .into());
+    }
+
+    let galaxies: Vec<Pos> = grid
+        .cells()
+        .filter(|&(_, char)| char == '#')
+        .map(|(pos, _)| pos)
+        .collect();
+    let mut rows = vec![true; grid.height()];
+    let mut cols = vec![true; grid.width()];
+    for &(row, col) in &galaxies {
+        rows[row] = false;
+        cols[col] = false;
+    }
+
+    Ok(Universe {
+        galaxies,
+        empty_rows: count_before(&rows),
+        empty_cols: count_before(&cols),
+    })
+}
+
+/// Counts how many of `empty` are true before each index, plus one more at the
+/// end for the total.
+fn count_before(empty: &[bool]) -> Vec<u64> {
+    let mut counts = vec![0];
+    for &empty in empty {
+        counts.push(counts[counts.len() - 1] + empty as u64);
+    }
+    counts
+}
+
+/// Adds up the shortest distance between every pair of galaxies once every
+/// empty row and column is `factor` times as big. The distance is the rows
+/// plus the columns apart, so each adds up on its own: with the coordinates
+/// sorted, the gap between the `i`th and the next is crossed by the `i + 1`
+/// galaxies before it times the `n - i - 1` after it.
+///
+/// * `universe`: the image
+/// * `factor`: how many rows or columns each empty one becomes
+pub fn distance_sum(universe: &Universe, factor: NonZeroU64) -> u128 {
+    let (mut rows, mut cols): (Vec<u128>, Vec<u128>) = universe
+        .galaxies
+        .iter()
+        .map(|&galaxy| universe.expanded(galaxy, factor))
+        .unzip();
+    rows.sort_unstable();
+    cols.sort_unstable();
+
+    let pairwise = |sorted: &[u128]| -> u128 {
+        let n = sorted.len() as u128;
+        (0..)
+            .zip(sorted.windows(2))
+            .map(|(i, pair)| (pair[1] - pair[0]) * (i + 1) * (n - i - 1))
+            .sum()
+    };
+    pairwise(&rows) + pairwise(&cols)
+}
+
+/// Cosmic Expansion: the sum of the distances between every pair of
+/// galaxies, once the empty rows and columns have grown.
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Universe;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_universe(input)
+    }
+
+    fn part_one(universe: &Self::Input) -> u64 {
+        distance_sum(universe, PART_ONE)
+            .try_into()
+            .expect("the distances don't fit in a u64")
+    }
+
+    fn part_two(universe: &Self::Input) -> u64 {
+        distance_sum(universe, PART_TWO)
+            .try_into()
+            .expect("the distances don't fit in a u64")
+    }
+
+    fn explain(universe: &Self::Input, trace: &mut Trace) {
+        let (rows, cols) = universe.empty();
+        let numbers = |empty: Vec<usize>| -> String {
+            let numbers: Vec<String> = empty.iter().map(|i| (i + 1).to_string()).collect();
+            match numbers.is_empty() {
+                true => "none".to_string(),
+                false => numbers.join(", "),
+            }
+        };
+        trace.line(format_args!("empty rows: {}", numbers(rows)));
+        trace.line(format_args!("empty columns: {}", numbers(cols)));
+
+        trace.heading("galaxies, from the top left");
+        for (number, &galaxy) in universe.galaxies.iter().enumerate() {
+            let (row, col) = galaxy;
+            let ((one_row, one_col), (two_row, two_col)) = (
+                universe.expanded(galaxy, PART_ONE),
+                universe.expanded(galaxy, PART_TWO),
+            );
+            trace.line(format_args!(
+                "{}: line {}, column {} moves to line {}, column {} \
+                 (line {}, column {} in part two)",
+                number + 1,
+                row + 1,
+                col + 1,
+                one_row + 1,
+                one_col + 1,
+                two_row + 1,
+                two_col + 1
+            ));
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use aoc::{source, trace, Solution};
+use day11::Day11;
+use std::{num::NonZeroU64, process};
+
+fn main() {
+    let (contents, flags) =
+        source::read_or_exit(Day11::DAY, env!("CARGO_MANIFEST_DIR"), &["--expansion="]);
+    let universe = Day11::parse(&contents).unwrap_or_else(|err| {
+        eprintln!("couldn't parse input: {err}");
+        process::exit(1);
+    });
+
+    for factor in flags
+        .iter()
+        .filter_map(|flag| flag.strip_prefix("--expansion="))
+    {
+        match factor.parse::<NonZeroU64>() {
+            Ok(factor) => println!(
+                "Expanded {factor} times: {}",
+                day11::distance_sum(&universe, factor)
+            ),
+            Err(_) => {
+                eprintln!("--expansion should be a whole number of at least 1, not `{factor}`");
+                process::exit(1);
+            }
+        }
+    }
+
+    println!("Answer 1: {}", Day11::part_one(&universe));
+    println!("Answer 2: {}", Day11::part_two(&universe));
+    trace::print_if_asked::<Day11>(&universe, &flags);
+}
@@ -0,0 +1,28 @@
+use std::num::NonZeroU64;
+
+use aoc::Solution;
+use day11::Day11;
+
+const EXAMPLE: &str = include_str!("../test.txt");
+
+#[test]
+fn part_one_example() {
+    let universe = Day11::parse(EXAMPLE).unwrap();
+    assert_eq!(universe.empty(), (vec![3, 7], vec![2, 5, 8]));
+    assert_eq!(Day11::part_one(&universe), 374);
+}
+
+#[test]
+fn other_expansion_factors() {
+    let universe = Day11::parse(EXAMPLE).unwrap();
+    let factor = |factor| NonZeroU64::new(factor).unwrap();
+    assert_eq!(day11::distance_sum(&universe, factor(1)), 292);
+    assert_eq!(day11::distance_sum(&universe, factor(10)), 1030);
+    assert_eq!(day11::distance_sum(&universe, factor(100)), 8410);
+}
+
+#[test]
+fn part_two_example() {
+    let universe = Day11::parse(EXAMPLE).unwrap();
+    assert_eq!(Day11::part_two(&universe), 82000210);
+}
@@ -0,0 +1,94 @@
+use std::num::NonZeroU64;
+
+use aoc::{
+    generate::{self, Rng},
+    Solution,
+};
+use day11::Day11;
+
+/// Copies every empty row and column to expand the image twice over, then
+/// adds up the distances between every pair of galaxies in the copy.
+fn expanded_twice(input: &str) -> u64 {
+    let mut rows: Vec<Vec<char>> = vec![];
+    for line in input.lines() {
+        rows.push(line.chars().collect());
+        if !line.contains('#') {
+            rows.push(line.chars().collect());
+        }
+    }
+    let width = rows[0].len();
+    for col in (0..width).rev() {
+        if rows.iter().all(|row| row[col] == '.') {
+            for row in &mut rows {
+                row.insert(col, '.');
+            }
+        }
+    }
+    pair_distances(&rows, |_, _| 0, |_, _| 0)
+}
+
+/// Adds up the distances between every pair of galaxies, one pair at a time,
+/// with the extra rows and columns between them from `rows_between` and
+/// `cols_between`.
+fn pair_distances(
+    grid: &[Vec<char>],
+    rows_between: impl Fn(usize, usize) -> u64,
+    cols_between: impl Fn(usize, usize) -> u64,
+) -> u64 {
+    let galaxies: Vec<(usize, usize)> = (0..grid.len())
+        .flat_map(|row| (0..grid[row].len()).map(move |col| (row, col)))
+        .filter(|&(row, col)| grid[row][col] == '#')
+        .collect();
+    let mut sum = 0;
+    for (i, &(row, col)) in galaxies.iter().enumerate() {
+        for &(other_row, other_col) in &galaxies[i + 1..] {
+            let (top, bottom) = (row.min(other_row), row.max(other_row));
+            let (left, right) = (col.min(other_col), col.max(other_col));
+            sum += (bottom - top + right - left) as u64
+                + rows_between(top, bottom)
+                + cols_between(left, right);
+        }
+    }
+    sum
+}
+
+/// Adds up the distances with every empty row and column `factor` times as
+/// big, counting the empty ones between each pair of galaxies.
+fn expanded(input: &str, factor: u64) -> u64 {
+    let grid: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+    let empty_row = |row: usize| grid[row].iter().all(|&c| c == '.');
+    let empty_col = |col: usize| grid.iter().all(|row| row[col] == '.');
+    pair_distances(
+        &grid,
+        |top, bottom| (top..bottom).filter(|&row| empty_row(row)).count() as u64 * (factor - 1),
+        |left, right| (left..right).filter(|&col| empty_col(col)).count() as u64 * (factor - 1),
+    )
+}
+
+#[test]
+fn matches_reference_on_random_inputs() {
+    for seed in 0..300 {
+        let mut rng = Rng::new(seed);
+        let (width, height) = (rng.range(1..=15) as usize, rng.range(1..=15) as usize);
+        let input = generate::galaxy_image(&mut rng, width, height, 15);
+        let universe = Day11::parse(&input).unwrap();
+
+        assert_eq!(
+            Day11::part_one(&universe),
+            expanded_twice(&input),
+            "seed {seed}:\n{input}"
+        );
+        assert_eq!(
+            Day11::part_two(&universe),
+            expanded(&input, 1_000_000),
+            "seed {seed}:\n{input}"
+        );
+        for factor in [1, 3, 10] {
+            assert_eq!(
+                day11::distance_sum(&universe, NonZeroU64::new(factor).unwrap()),
+                expanded(&input, factor) as u128,
+                "seed {seed}, expanded {factor} times:\n{input}"
+            );
+        }
+    }
+}
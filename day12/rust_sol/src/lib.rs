@@ -0,0 +1,204 @@
+use std::fmt;
+
+use aoc::{
+    parse::{
+        self, lines, map, pair, preceded, separated, spaces, tag, take_while1, try_map, unsigned,
+    },
+    trace::Trace,
+    ParseError, Solution,
+};
+
+/// The condition of a spring in the records.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Spring {
+    /// `.`
+    Operational,
+    /// `#`
+    Damaged,
+    /// `?`
+    Unknown,
+}
+
+impl Spring {
+    fn from_char(char: char) -> Option<Spring> {
+        match char {
+            '.' => Some(Spring::Operational),
+            '#' => Some(Spring::Damaged),
+            '?' => Some(Spring::Unknown),
+            _ => None,
+        }
+    }
+
+    pub fn to_char(self) -> char {
+        match self {
+            Spring::Operational => '.',
+            Spring::Damaged => '#',
+            Spring::Unknown => '?',
+        }
+    }
+}
+
+/// A row of the records: the springs and the sizes of the groups of damaged
+/// springs in it, in order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Row {
+    springs: Vec<Spring>,
+    groups: Vec<usize>,
+}
+
+impl Row {
+    pub fn new(springs: Vec<Spring>, groups: Vec<usize>) -> Self {
+        Row { springs, groups }
+    }
+
+    pub fn springs(&self) -> &[Spring] {
+        &self.springs
+    }
+
+    pub fn groups(&self) -> &[usize] {
+        &self.groups
+    }
+
+    /// Unfolds the row like part two does: `copies` copies of the springs with
+    /// a `?` between each, and the groups repeated as many times.
+    pub fn unfold(&self, copies: usize) -> Row {
+        let mut springs = vec![];
+        for copy in 0..copies {
+            if copy > 0 {
+                springs.push(Spring::Unknown);
+            }
+            springs.extend(&self.springs);
+        }
+        Row {
+            springs,
+            groups: self.groups.repeat(copies),
+        }
+    }
+
+    /// Counts the ways to make every `?` operational or damaged so the groups
+    /// of damaged springs are the row's groups, or `None` if there are more
+    /// than fit in a `u64`.
+    ///
+    /// The count for the springs from `i` on and the groups from `g` on only
+    /// depends on (`i`, `g`): the spring at `i` is either operational, leaving
+    /// (`i + 1`, `g`), or starts group `g`, which then has to fit and be
+    /// followed by an operational spring, leaving (`i + size + 1`, `g + 1`).
+    /// The table is filled one group at a time from the last, so only the
+    /// counts for the group after are kept.
+    pub fn arrangements(&self) -> Option<u64> {
+        let (springs, groups) = (&self.springs, &self.groups);
+        let n = springs.len();
+
+        // a group fits where none of its springs are operational, i.e. the
+        // operational springs before its start and after its end are the same
+        let mut operational_before = vec![0];
+        for &spring in springs {
+            let before = operational_before[operational_before.len() - 1];
+            operational_before.push(before + (spring == Spring::Operational) as usize);
+        }
+        let fits = |start: usize, size: usize| {
+            size <= n - start
+                && operational_before[start + size] == operational_before[start]
+                && springs.get(start + size) != Some(&Spring::Damaged)
+        };
+
+        // with no groups left, the rest of the springs have to be operational
+        let mut after = vec![1u64; n + 1];
+        for i in (0..n).rev() {
+            after[i] = match springs[i] {
+                Spring::Damaged => 0,
+                _ => after[i + 1],
+            };
+        }
+
+        for &size in groups.iter().rev() {
+            // at the end of the springs there's no room for the group
+            let mut ways = vec![0u64; n + 1];
+            for i in (0..n).rev() {
+                let mut count = 0;
+                if springs[i] != Spring::Damaged {
+                    count = ways[i + 1];
+                }
+                if springs[i] != Spring::Operational && fits(i, size) {
+                    count = count.checked_add(after[(i + size + 1).min(n)])?;
+                }
+                ways[i] = count;
+            }
+            after = ways;
+        }
+        Some(after[0])
+    }
+}
+
+impl fmt::Display for Row {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let springs: String = self.springs.iter().map(|spring| spring.to_char()).collect();
+        let groups: Vec<String> = self.groups.iter().map(usize::to_string).collect();
+        write!(f, "{springs} {}", groups.join(","))
+    }
+}
+
+/// Reads the records, one row per line, e.g. `???.### 1,1,3`. Every group
+/// has at least one spring.
+pub fn parse_rows(contents: &str) -> Result<Vec<Row>, parse::Error> {
+    let springs = map(
+        take_while1("springs", |c| Spring::from_char(c).is_some()),
+        |springs: &str| springs.chars().filter_map(Spring::from_char).collect(),
+    );
+    let group = try_map(unsigned::<usize>(), |size| match size {
+        0 => Err("a group has at least one spring".to_string()),
+        size => Ok(size),
+    });
+    let row = map(
+        pair(springs, preceded(spaces(), separated(group, tag(",")))),
+        |(springs, groups)| Row { springs, groups },
+    );
+    parse::run(lines(row), contents)
+}
+
+/// Adds up the arrangements of every row, each unfolded `copies` times, or
+/// `None` if the sum doesn't fit in a `u64`.
+pub fn total_arrangements(rows: &[Row], copies: usize) -> Option<u64> {
+    rows.iter().try_fold(0u64, |sum, row| {
+        sum.checked_add(row.unfold(copies).arrangements()?)
+    })
+}
+
+/// Hot Springs: how many ways the unknown springs can be filled in to match
+/// the damaged groups, before and after unfolding the records.
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Vec<Row>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(parse_rows(input)?)
+    }
+
+    fn part_one(rows: &Self::Input) -> u64 {
+        total_arrangements(rows, 1).expect("the arrangements don't fit in a u64")
+    }
+
+    fn part_two(rows: &Self::Input) -> u64 {
+        total_arrangements(rows, 5).expect("the arrangements don't fit in a u64")
+    }
+
+    fn explain(rows: &Self::Input, trace: &mut Trace) {
+        for (number, row) in rows.iter().enumerate() {
+            let count = |copies: usize| match row.unfold(copies).arrangements() {
+                Some(count) => count.to_string(),
+                None => "too many".to_string(),
+            };
+            trace.line(format_args!(
+                "{}: {row} has {} arrangement(s), {} unfolded",
+                number + 1,
+                count(1),
+                count(5)
+            ));
+        }
+    }
+}
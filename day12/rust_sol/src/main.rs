@@ -0,0 +1,15 @@
+use aoc::{source, trace, Solution};
+use day12::Day12;
+use std::process;
+
+fn main() {
+    let (contents, flags) = source::read_or_exit(Day12::DAY, env!("CARGO_MANIFEST_DIR"), &[]);
+    let input = Day12::parse(&contents).unwrap_or_else(|err| {
+        eprintln!("couldn't parse input: {err}");
+        process::exit(1);
+    });
+
+    println!("Answer 1: {}", Day12::part_one(&input));
+    println!("Answer 2: {}", Day12::part_two(&input));
+    trace::print_if_asked::<Day12>(&input, &flags);
+}
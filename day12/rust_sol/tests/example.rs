@@ -0,0 +1,36 @@
+use aoc::Solution;
+use day12::Day12;
+
+const EXAMPLE: &str = include_str!("../test.txt");
+
+#[test]
+fn part_one_example() {
+    let rows = Day12::parse(EXAMPLE).unwrap();
+    let counts: Vec<Option<u64>> = rows.iter().map(|row| row.arrangements()).collect();
+    assert_eq!(counts, [1, 4, 1, 1, 4, 10].map(Some));
+    assert_eq!(Day12::part_one(&rows), 21);
+}
+
+#[test]
+fn part_two_example() {
+    let rows = Day12::parse(EXAMPLE).unwrap();
+    let counts: Vec<Option<u64>> = rows
+        .iter()
+        .map(|row| row.unfold(5).arrangements())
+        .collect();
+    assert_eq!(counts, [1, 16384, 1, 16, 2500, 506250].map(Some));
+    assert_eq!(Day12::part_two(&rows), 525152);
+}
+
+#[test]
+fn unfolds_with_unknown_springs_between() {
+    let rows = Day12::parse(".# 1").unwrap();
+    assert_eq!(rows[0].unfold(5).to_string(), ".#?.#?.#?.#?.# 1,1,1,1,1");
+}
+
+#[test]
+fn rejects_empty_groups() {
+    for input in ["???.### 1,0,3", "???.### ", "?x? 1", "??? 1,,1"] {
+        assert!(Day12::parse(input).is_err(), "{input:?}");
+    }
+}
@@ -0,0 +1,78 @@
+use aoc::{
+    generate::{self, Rng},
+    Solution,
+};
+use day12::Day12;
+
+/// Tries every way to fill in the `?`s and counts the ones whose groups of
+/// `#`s match.
+fn brute_force(springs: &str, groups: &[usize]) -> u64 {
+    let unknown: Vec<usize> = springs
+        .char_indices()
+        .filter(|&(_, c)| c == '?')
+        .map(|(i, _)| i)
+        .collect();
+    let mut count = 0;
+    for filled in 0..1u64 << unknown.len() {
+        let mut row: Vec<char> = springs.chars().collect();
+        for (bit, &i) in unknown.iter().enumerate() {
+            row[i] = if filled >> bit & 1 == 1 { '#' } else { '.' };
+        }
+        let found: Vec<usize> = row
+            .split(|&c| c == '.')
+            .filter(|group| !group.is_empty())
+            .map(|group| group.len())
+            .collect();
+        if found == groups {
+            count += 1;
+        }
+    }
+    count
+}
+
+/// Unfolds a row from the input `copies` times and brute forces it.
+fn brute_force_unfolded(line: &str, copies: usize) -> u64 {
+    let (springs, groups) = line.split_once(' ').unwrap();
+    let groups: Vec<usize> = groups.split(',').map(|n| n.parse().unwrap()).collect();
+    brute_force(&vec![springs; copies].join("?"), &groups.repeat(copies))
+}
+
+#[test]
+fn matches_brute_force_on_random_inputs() {
+    for seed in 0..300 {
+        let input = generate::spring_records(&mut Rng::new(seed), 5, 14, 60);
+        let rows = Day12::parse(&input).unwrap();
+
+        let expected: u64 = input
+            .lines()
+            .map(|line| brute_force_unfolded(line, 1))
+            .sum();
+        assert_eq!(Day12::part_one(&rows), expected, "seed {seed}:\n{input}");
+    }
+}
+
+#[test]
+fn unfolded_rows_match_brute_force() {
+    for seed in 0..300 {
+        let input = generate::spring_records(&mut Rng::new(seed), 3, 5, 50);
+        let rows = Day12::parse(&input).unwrap();
+
+        for (row, line) in rows.iter().zip(input.lines()) {
+            for copies in [2, 3] {
+                assert_eq!(
+                    row.unfold(copies).arrangements(),
+                    Some(brute_force_unfolded(line, copies)),
+                    "seed {seed}, {copies} copies of {line}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn overflow_is_caught() {
+    // ten groups of one among 2000 unknown springs can go in C(1991, 10)
+    // ways, about 10^26
+    let rows = Day12::parse(&format!("{} 1,1,1,1,1,1,1,1,1,1", "?".repeat(2000))).unwrap();
+    assert_eq!(day12::total_arrangements(&rows, 1), None);
+}
@@ -0,0 +1,157 @@
+use aoc::{trace::Trace, ParseError, Solution};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::fmt;
+
+pub mod parser;
+
+pub use parser::parse_games;
+
+/// One game: its id and the sets of cubes shown from the bag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    id: u32,
+    sets: Vec<Set>,
+}
+
+impl Game {
+    pub fn new(id: u32, sets: Vec<Set>) -> Self {
+        Game { id, sets }
+    }
+
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn sets(&self) -> &[Set] {
+        &self.sets
+    }
+}
+
+/// A number of red, green and blue cubes, either shown at once or in the bag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Set {
+    red: u32,
+    green: u32,
+    blue: u32,
+}
+
+impl Set {
+    pub fn new(red: u32, green: u32, blue: u32) -> Self {
+        Set { red, green, blue }
+    }
+
+    pub fn red(&self) -> u32 {
+        self.red
+    }
+
+    pub fn green(&self) -> u32 {
+        self.green
+    }
+
+    pub fn blue(&self) -> u32 {
+        self.blue
+    }
+}
+
+impl fmt::Display for Set {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} red, {} green, {} blue",
+            self.red, self.green, self.blue
+        )
+    }
+}
+
+/// The bag part one asks about: 12 red, 13 green and 14 blue cubes.
+pub const BAG: Set = Set {
+    red: 12,
+    green: 13,
+    blue: 14,
+};
+
+/// Cube Conundrum: which games are possible with a given bag of cubes, and
+/// the fewest cubes each game needs.
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Game>;
+    type Answer1 = u64;
+    type Answer2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(parser::parse_games(input)?)
+    }
+
+    fn part_one(games: &Self::Input) -> u64 {
+        let possible = |game: &&Game| is_game_possible(game, &BAG);
+        let id = |game: &Game| u64::from(game.id);
+
+        #[cfg(feature = "parallel")]
+        if aoc::parallel::enabled() {
+            return games.par_iter().filter(possible).map(id).sum();
+        }
+        games.iter().filter(possible).map(id).sum()
+    }
+
+    fn part_two(games: &Self::Input) -> u128 {
+        #[cfg(feature = "parallel")]
+        if aoc::parallel::enabled() {
+            return games.par_iter().map(get_power_set).sum();
+        }
+        games.iter().map(get_power_set).sum()
+    }
+
+    fn explain(games: &Self::Input, trace: &mut Trace) {
+        for game in games {
+            let minimum = get_minimum_set(game);
+            let power = get_power_set(game);
+            match first_impossible_set(game, &BAG) {
+                Some((index, set)) => trace.line(format_args!(
+                    "game {}: impossible, set {} ({set}) doesn't fit in the bag ({BAG}); \
+                     needs {minimum}, power {power}",
+                    game.id,
+                    index + 1
+                )),
+                None => trace.line(format_args!(
+                    "game {}: possible; needs {minimum}, power {power}",
+                    game.id
+                )),
+            }
+        }
+    }
+}
+
+/// Gets the fewest cubes of each colour a game could have been played with.
+pub fn get_minimum_set(game: &Game) -> Set {
+    let max_red = game.sets.iter().map(|set| set.red).max().unwrap_or(0);
+    let max_green = game.sets.iter().map(|set| set.green).max().unwrap_or(0);
+    let max_blue = game.sets.iter().map(|set| set.blue).max().unwrap_or(0);
+
+    Set::new(max_red, max_green, max_blue)
+}
+
+/// Gets the power of a game's minimum set: its red, green and blue cubes
+/// multiplied together. Three `u32` counts always fit in a `u128`.
+pub fn get_power_set(game: &Game) -> u128 {
+    let set = get_minimum_set(game);
+    u128::from(set.red) * u128::from(set.green) * u128::from(set.blue)
+}
+
+/// Checks whether a game could have been played with the cubes in `bag`,
+/// i.e. no set shows more of a colour than the bag holds.
+pub fn is_game_possible(game: &Game, bag: &Set) -> bool {
+    first_impossible_set(game, bag).is_none()
+}
+
+/// Finds the first set showing more of a colour than `bag` holds, with its
+/// index in the game.
+pub fn first_impossible_set<'a>(game: &'a Game, bag: &Set) -> Option<(usize, &'a Set)> {
+    game.sets
+        .iter()
+        .enumerate()
+        .find(|(_, set)| set.red > bag.red || set.blue > bag.blue || set.green > bag.green)
+}
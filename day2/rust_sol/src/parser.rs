@@ -0,0 +1,89 @@
+use aoc::parse::{
+    self, alt, delimited, end, labelled, lines, map, pair, preceded, separated, spaces, spanned,
+    tag, terminated, try_map, unsigned, word, Error, ErrorKind, Found, Input, Parser,
+};
+
+use crate::{Game, Set};
+
+/// Parses every non-blank line of the input as a game record.
+///
+/// Grammar (whitespace is allowed between any two tokens):
+///
+/// ```text
+/// game  := "Game" number ":" set (";" set)*
+/// set   := draw ("," draw)*
+/// draw  := number color
+/// color := "red" | "green" | "blue"
+/// ```
+pub fn parse_games(content: &str) -> Result<Vec<Game>, Error> {
+    let blank = map(end(), |_| None);
+    let games = parse::run(
+        lines(preceded(spaces(), alt(map(game(), Some), blank))),
+        content,
+    )?;
+
+    Ok(games.into_iter().flatten().collect())
+}
+
+/// Parses a single `Game N: ...` record.
+pub fn parse_game(line: &str) -> Result<Game, Error> {
+    parse::run(game(), line)
+}
+
+fn game<'a>() -> impl Parser<'a, Game> {
+    let id = delimited(
+        spaces(),
+        labelled("Game", unsigned()),
+        pair(spaces(), tag(":")),
+    );
+    let sets = terminated(separated(set(), token(";")), spaces());
+
+    map(pair(id, sets), |(id, sets)| Game::new(id, sets))
+}
+
+/// Parses `tag` and any spaces after it. The spaces before it belong to
+/// whatever came first, so a missing separator fails without reading anything
+/// and ends the list.
+fn token<'a>(tag: &'static str) -> impl Parser<'a, &'a str> {
+    terminated(parse::tag(tag), spaces())
+}
+
+fn set<'a>() -> impl Parser<'a, Set> {
+    let draw = pair(
+        preceded(spaces(), unsigned()),
+        delimited(spaces(), spanned(color()), spaces()),
+    );
+    let draws = separated(draw, token(","));
+
+    move |input: Input<'a>| {
+        let (draws, rest) = draws.parse(input)?;
+        let (mut red, mut green, mut blue) = (None, None, None);
+        for (number, color) in draws {
+            let slot = match color.value {
+                "red" => &mut red,
+                "green" => &mut green,
+                _ => &mut blue,
+            };
+            // the error points at the second draw of the colour, not the set
+            if slot.replace(number).is_some() {
+                return Err(Error {
+                    span: color.span,
+                    kind: ErrorKind::Invalid(format!("`{}` drawn twice in one set", color.value)),
+                    found: Found::Text(color.value.to_string()),
+                });
+            }
+        }
+
+        let set = Set::new(red.unwrap_or(0), green.unwrap_or(0), blue.unwrap_or(0));
+        Ok((set, rest))
+    }
+}
+
+fn color<'a>() -> impl Parser<'a, &'static str> {
+    try_map(word(), |color| match color {
+        "red" => Ok("red"),
+        "green" => Ok("green"),
+        "blue" => Ok("blue"),
+        other => Err(format!("unknown color `{other}`")),
+    })
+}
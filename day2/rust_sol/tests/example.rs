@@ -0,0 +1,26 @@
+use aoc::{trace::Trace, Solution};
+use day2::Day2;
+
+const EXAMPLE: &str = include_str!("../test.txt");
+
+#[test]
+fn part_one_example() {
+    let input = Day2::parse(EXAMPLE).unwrap();
+    assert_eq!(Day2::part_one(&input), 8);
+}
+
+#[test]
+fn part_two_example() {
+    let input = Day2::parse(EXAMPLE).unwrap();
+    assert_eq!(Day2::part_two(&input), 2286);
+}
+
+#[test]
+fn explains_the_set_that_breaks_a_game() {
+    let input = Day2::parse(EXAMPLE).unwrap();
+    let mut trace = Trace::new(10);
+    Day2::explain(&input, &mut trace);
+
+    assert_eq!(trace.lines().len(), 5);
+    assert!(trace.lines()[2].starts_with("game 3: impossible, set 1 (20 red, 8 green, 6 blue)"));
+}
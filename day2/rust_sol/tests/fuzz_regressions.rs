@@ -0,0 +1,22 @@
+//! Inputs the fuzz targets in `fuzz/` found crashes on.
+
+use aoc::Solution;
+use day2::Day2;
+
+#[test]
+fn sums_big_powers() {
+    let games = Day2::parse("Game 1: 100000 red, 100000 green, 100000 blue").unwrap();
+
+    assert_eq!(Day2::part_two(&games), 100000 * 100000 * 100000);
+
+    let games = Day2::parse("Game 1: 4294967295 red, 4294967295 green, 4294967295 blue").unwrap();
+    let max = u128::from(u32::MAX);
+    assert_eq!(Day2::part_two(&games), max * max * max);
+}
+
+#[test]
+fn sums_big_ids() {
+    let games = Day2::parse("Game 4294967295: 1 red\nGame 4294967295: 1 red").unwrap();
+
+    assert_eq!(Day2::part_one(&games), 2 * 4294967295);
+}
@@ -0,0 +1,64 @@
+use aoc::parse::{ErrorKind, Found};
+use day2::{parse_games, parser::parse_game, Game, Set};
+
+#[test]
+fn parses_a_game() {
+    let game = parse_game("Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red").unwrap();
+    assert_eq!(
+        game,
+        Game::new(3, vec![Set::new(20, 8, 6), Set::new(4, 0, 5)])
+    );
+}
+
+#[test]
+fn allows_extra_whitespace() {
+    let game = parse_game("  Game  7 :3 red ,  4\tblue;1   green  ").unwrap();
+    assert_eq!(
+        game,
+        Game::new(7, vec![Set::new(3, 0, 4), Set::new(0, 1, 0)])
+    );
+
+    // blank lines between games are skipped
+    let games = parse_games("Game 1: 1 red\n\n   \nGame 2: 2 blue\n").unwrap();
+    assert_eq!(games.iter().map(Game::id).collect::<Vec<_>>(), [1, 2]);
+}
+
+#[test]
+fn errors_point_at_the_line_and_column() {
+    let err = parse_games("Game 1: 1 red\nGame 2: 3 purple, 4 blue\n").unwrap_err();
+    assert_eq!((err.span.line, err.span.column), (2, 11));
+    assert_eq!(
+        err.kind,
+        ErrorKind::Invalid("unknown color `purple`".to_string())
+    );
+    assert_eq!(err.to_string(), "line 2, column 11: unknown color `purple`");
+
+    let err = parse_games("Game 1: 1 red, \n").unwrap_err();
+    assert_eq!((err.span.line, err.span.column), (1, 16));
+    assert_eq!(err.found, Found::EndOfLine);
+}
+
+#[test]
+fn a_missing_game_id_is_an_error() {
+    let err = parse_game("Game : 3 red").unwrap_err();
+    assert_eq!(err.span.column, 6);
+    assert_eq!(err.kind, ErrorKind::Expected("a number"));
+    assert_eq!(err.found, Found::Text(":".to_string()));
+
+    let err = parse_game("3 red, 4 blue").unwrap_err();
+    assert_eq!(err.kind, ErrorKind::Tag("Game"));
+}
+
+#[test]
+fn a_colour_drawn_twice_points_at_the_second_draw() {
+    let err = parse_games("Game 1: 2 red\nGame 2: 1 blue, 2 red, 3 blue; 4 green\n").unwrap_err();
+    assert_eq!(
+        err.kind,
+        ErrorKind::Invalid("`blue` drawn twice in one set".to_string())
+    );
+    assert_eq!((err.span.line, err.span.column), (2, 26));
+    assert_eq!(err.found, Found::Text("blue".to_string()));
+
+    // the same colour in different sets is fine
+    assert!(parse_game("Game 1: 1 blue; 3 blue").is_ok());
+}
@@ -0,0 +1,49 @@
+use aoc::{
+    generate::{self, Rng},
+    Solution,
+};
+use day2::Day2;
+
+/// Reads the most cubes of each colour shown in every game, splitting the
+/// lines apart by hand.
+fn maximums(input: &str) -> Vec<(u32, [u32; 3])> {
+    input
+        .lines()
+        .map(|line| {
+            let (game, sets) = line.split_once(": ").unwrap();
+            let id = game.trim_start_matches("Game ").parse().unwrap();
+            let mut max = [0; 3];
+            for draw in sets.split([';', ',']) {
+                let (count, color) = draw.trim().split_once(' ').unwrap();
+                let index = ["red", "green", "blue"]
+                    .iter()
+                    .position(|name| *name == color)
+                    .unwrap();
+                max[index] = max[index].max(count.parse().unwrap());
+            }
+            (id, max)
+        })
+        .collect()
+}
+
+#[test]
+fn matches_reference_on_random_inputs() {
+    for seed in 0..500 {
+        let input = generate::cube_games(&mut Rng::new(seed), 20, 5, 20);
+        let games = Day2::parse(&input).unwrap();
+        let maximums = maximums(&input);
+
+        let possible: u64 = maximums
+            .iter()
+            .filter(|(_, [red, green, blue])| *red <= 12 && *green <= 13 && *blue <= 14)
+            .map(|(id, _)| u64::from(*id))
+            .sum();
+        let power: u128 = maximums
+            .iter()
+            .map(|(_, max)| max.iter().map(|&count| u128::from(count)).product::<u128>())
+            .sum();
+
+        assert_eq!(Day2::part_one(&games), possible, "seed {seed}:\n{input}");
+        assert_eq!(Day2::part_two(&games), power, "seed {seed}:\n{input}");
+    }
+}
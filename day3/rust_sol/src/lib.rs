@@ -0,0 +1,248 @@
+use aoc::{
+    grid::{Grid, Pos},
+    parse::{self, alt, lines, many, map, spanned, take_while1, unsigned, Spanned},
+    trace::Trace,
+    ParseError, Solution,
+};
+
+pub mod render;
+
+use render::Gear;
+
+/// A number in the engine schematic and where it is: columns `col_start` to
+/// `col_end` (inclusive) of line `line`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Part {
+    number: i32,
+    col_start: usize,
+    col_end: usize,
+    line: usize,
+}
+
+impl Part {
+    pub fn new(number: i32, col_start: usize, col_end: usize, line: usize) -> Self {
+        Part {
+            number,
+            col_start,
+            col_end,
+            line,
+        }
+    }
+
+    pub fn number(&self) -> i32 {
+        self.number
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Gets the first and last column of the number (inclusive).
+    pub fn columns(&self) -> (usize, usize) {
+        (self.col_start, self.col_end)
+    }
+
+    /// Checks whether one of the number's digits is at `row`, `col`.
+    pub fn is_here(&self, row: usize, col: usize) -> bool {
+        if self.line == row && col >= self.col_start && col <= self.col_end {
+            return true;
+        }
+        false
+    }
+
+    /// Checks whether the number is a part number, i.e. a symbol touches it,
+    /// diagonals included.
+    ///
+    /// * `grid`: the schematic the number is in
+    pub fn is_valid(&self, grid: &Grid) -> bool {
+        self.symbol(grid).is_some()
+    }
+
+    /// Finds the first symbol touching the number, diagonals included, as the
+    /// symbol and its row and column.
+    ///
+    /// * `grid`: the schematic the number is in
+    pub fn symbol(&self, grid: &Grid) -> Option<(char, usize, usize)> {
+        grid.around(self.line, self.col_start..=self.col_end)
+            .find_map(|(row, col)| {
+                let char = grid.get((row, col))?;
+                check_symbol(char).then_some((char, row, col))
+            })
+    }
+}
+
+/// Checks whether a character is a symbol: anything but a digit or `.`.
+pub fn check_symbol(character: char) -> bool {
+    !matches!(
+        character,
+        '0' | '1' | '2' | '3' | '4' | '5' | '6' | '7' | '8' | '9' | '.'
+    )
+}
+
+/// The engine schematic: its characters and the numbers in it.
+pub struct Schematic {
+    parts: Vec<Part>,
+    grid: Grid,
+}
+
+impl Schematic {
+    /// Gets every number in the schematic, valid or not.
+    pub fn parts(&self) -> &[Part] {
+        &self.parts
+    }
+
+    pub fn grid(&self) -> &Grid {
+        &self.grid
+    }
+
+    /// Gets every `*` as (row, column) with the numbers touching it, in
+    /// reading order. The ones touching exactly two numbers are gears.
+    pub fn stars(&self) -> Vec<(Pos, Vec<i32>)> {
+        get_gear_locations(&self.grid)
+            .into_iter()
+            .map(|star| {
+                let numbers = numbers_touching_gear(&self.grid, star)
+                    .into_iter()
+                    .map(|location| get_part_number(&self.parts, location))
+                    .collect();
+                (star, numbers)
+            })
+            .collect()
+    }
+}
+
+/// Reads a schematic: its grid of characters and the numbers in it. The
+/// schematic has to be a rectangle of ASCII characters.
+pub fn parse_schematic(contents: &str) -> Result<Schematic, ParseError> {
+    let grid = Grid::parse(contents)?;
+
+    Ok(Schematic {
+        parts: build_number_list(contents)?,
+        grid,
+    })
+}
+
+/// Gear Ratios: which numbers touch a symbol, and the gears (`*` touching
+/// exactly two numbers).
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Input = Schematic;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_schematic(input)
+    }
+
+    fn part_one(schematic: &Self::Input) -> i64 {
+        schematic
+            .parts
+            .iter()
+            .filter(|part| part.is_valid(&schematic.grid))
+            .map(|part| part.number as i64)
+            .sum()
+    }
+
+    fn part_two(schematic: &Self::Input) -> i64 {
+        render::gears(schematic).iter().map(Gear::ratio).sum()
+    }
+
+    fn explain(schematic: &Self::Input, trace: &mut Trace) {
+        let (grid, parts) = (&schematic.grid, &schematic.parts);
+
+        trace.heading("part numbers");
+        for part in parts {
+            let (start, _) = part.columns();
+            let at = format!("line {}, column {}", part.line + 1, start + 1);
+            match part.symbol(grid) {
+                Some((symbol, row, col)) => trace.line(format_args!(
+                    "{} at {at}: next to `{symbol}` at line {}, column {}",
+                    part.number,
+                    row + 1,
+                    col + 1
+                )),
+                None => trace.line(format_args!(
+                    "{} at {at}: not a part, no symbol next to it",
+                    part.number
+                )),
+            }
+        }
+
+        trace.heading("gears");
+        for ((row, col), numbers) in schematic.stars() {
+            let at = format!("line {}, column {}", row + 1, col + 1);
+            match numbers[..] {
+                [one, two] => trace.line(format_args!(
+                    "gear at {at}: {one} * {two} = {}",
+                    one as i64 * two as i64
+                )),
+                _ => trace.line(format_args!(
+                    "`*` at {at}: touches {} number(s), not a gear",
+                    numbers.len()
+                )),
+            }
+        }
+    }
+}
+
+/// Gets the number with a digit at `location` (row, column), or 0 if there
+/// isn't one.
+pub fn get_part_number(parts: &[Part], location: Pos) -> i32 {
+    for part in parts.iter() {
+        if part.is_here(location.0, location.1) {
+            return part.number;
+        }
+    }
+    0
+}
+
+/// Finds the numbers touching a gear, diagonals included, as the location of
+/// one digit of each: the first digit of each run in the box around the gear.
+///
+/// * `grid`: the schematic
+/// * `gear`: the gear's row and column
+pub fn numbers_touching_gear(grid: &Grid, gear: Pos) -> Vec<Pos> {
+    let is_digit = |pos: Pos| grid.get(pos).is_some_and(|char| char.is_ascii_digit());
+    let around: Vec<Pos> = grid.around(gear.0, gear.1..=gear.1).collect();
+
+    around
+        .iter()
+        .copied()
+        .filter(|&(row, col)| {
+            // a digit right after another one in the box is the same number
+            let after_digit = col
+                .checked_sub(1)
+                .is_some_and(|left| around.contains(&(row, left)) && is_digit((row, left)));
+            is_digit((row, col)) && !after_digit
+        })
+        .collect()
+}
+
+/// Finds every `*` in the schematic as (row, column). Only the ones touching
+/// exactly two numbers are gears.
+pub fn get_gear_locations(grid: &Grid) -> Vec<Pos> {
+    grid.cells()
+        .filter(|&(_, char)| char == '*')
+        .map(|(pos, _)| pos)
+        .collect()
+}
+
+/// Finds every number in the schematic (which has to be ASCII).
+pub fn build_number_list(contents: &str) -> Result<Vec<Part>, ParseError> {
+    let number = map(spanned(unsigned::<i32>()), Some);
+    let other = map(take_while1("a symbol", |c| !c.is_ascii_digit()), |_| None);
+    let rows = parse::run(lines(many(alt(number, other))), contents)?;
+
+    Ok(rows
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|Spanned { value, span }| {
+            let col_start = span.column - 1;
+            Part::new(value, col_start, col_start + span.len() - 1, span.line - 1)
+        })
+        .collect())
+}
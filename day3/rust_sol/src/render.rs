@@ -0,0 +1,222 @@
+//! Draws a schematic with what the solver found marked on it: which numbers
+//! `Part::is_valid` accepted, and which `*`s `Schematic::stars` found to be
+//! gears.
+
+use std::fmt::Write;
+
+use crate::Schematic;
+
+const GREEN: &str = "\x1b[32m";
+const RED: &str = "\x1b[31m";
+/// Bold black on yellow
+const HIGHLIGHT: &str = "\x1b[1;30;43m";
+const RESET: &str = "\x1b[0m";
+
+/// How to draw the schematic in a terminal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    /// Part numbers green, other numbers red and gears highlighted
+    Ansi,
+    /// No colours, the numbers that aren't parts are listed after each line
+    Plain,
+}
+
+/// What a cell of the schematic is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mark {
+    /// A symbol that isn't a gear, or `.`
+    None,
+    /// A digit of a part number
+    Part,
+    /// A digit of a number no symbol touches
+    NotPart,
+    /// A `*` touching exactly two numbers
+    Gear,
+}
+
+/// A `*` touching exactly two numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Gear {
+    pub row: usize,
+    pub col: usize,
+    pub numbers: [i32; 2],
+}
+
+impl Gear {
+    pub fn ratio(&self) -> i64 {
+        self.numbers[0] as i64 * self.numbers[1] as i64
+    }
+}
+
+/// Marks every cell of the schematic.
+pub fn marks(schematic: &Schematic) -> Vec<Vec<Mark>> {
+    let grid = schematic.grid();
+    let mut marks: Vec<Vec<Mark>> = grid
+        .rows()
+        .iter()
+        .map(|row| vec![Mark::None; row.len()])
+        .collect();
+
+    for part in schematic.parts() {
+        let mark = match part.is_valid(grid) {
+            true => Mark::Part,
+            false => Mark::NotPart,
+        };
+        let (start, end) = part.columns();
+        marks[part.line()][start..=end].fill(mark);
+    }
+    for gear in gears(schematic) {
+        marks[gear.row][gear.col] = Mark::Gear;
+    }
+    marks
+}
+
+/// Finds the gears, in reading order.
+pub fn gears(schematic: &Schematic) -> Vec<Gear> {
+    schematic
+        .stars()
+        .into_iter()
+        .filter_map(|((row, col), numbers)| {
+            Some(Gear {
+                row,
+                col,
+                numbers: numbers.try_into().ok()?,
+            })
+        })
+        .collect()
+}
+
+/// Draws the schematic for a terminal, one line per row. Rows with gears end
+/// with their ratios.
+///
+/// * `schematic`: the schematic to draw
+/// * `style`: with or without colours
+pub fn render(schematic: &Schematic, style: Style) -> String {
+    let marks = marks(schematic);
+    let notes = notes(schematic, style);
+    let mut output = String::new();
+
+    for (row, line) in schematic.grid().rows().iter().enumerate() {
+        let mut current = Mark::None;
+        for (col, &char) in line.iter().enumerate() {
+            let mark = marks[row][col];
+            if style == Style::Ansi && mark != current {
+                if current != Mark::None {
+                    output += RESET;
+                }
+                output += colour(mark);
+                current = mark;
+            }
+            output.push(char);
+        }
+        if current != Mark::None {
+            output += RESET;
+        }
+        if !notes[row].is_empty() {
+            let _ = write!(output, "  {}", notes[row].join("; "));
+        }
+        output.push('\n');
+    }
+    output
+}
+
+fn colour(mark: Mark) -> &'static str {
+    match mark {
+        Mark::None => "",
+        Mark::Part => GREEN,
+        Mark::NotPart => RED,
+        Mark::Gear => HIGHLIGHT,
+    }
+}
+
+/// Gets what to write after each row: its gears' ratios, and without colours
+/// the numbers that aren't parts.
+fn notes(schematic: &Schematic, style: Style) -> Vec<Vec<String>> {
+    let mut notes = vec![vec![]; schematic.grid().height()];
+
+    if style == Style::Plain {
+        let grid = schematic.grid();
+        for part in schematic.parts() {
+            if !part.is_valid(grid) {
+                notes[part.line()].push(format!("not a part: {}", part.number()));
+            }
+        }
+    }
+    for gear in gears(schematic) {
+        let [one, two] = gear.numbers;
+        notes[gear.row].push(format!(
+            "gear at column {}: {one} * {two} = {}",
+            gear.col + 1,
+            gear.ratio()
+        ));
+    }
+    notes
+}
+
+/// Draws the schematic as an HTML page, for grids too big for a terminal.
+/// Hovering over a gear shows its ratio.
+pub fn html(schematic: &Schematic) -> String {
+    let marks = marks(schematic);
+    let gears = gears(schematic);
+    let notes = notes(schematic, Style::Ansi);
+    let mut output = String::from(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>Engine schematic</title>\n<style>\n\
+         body { background: #111; color: #888; }\n\
+         .part { color: #3c3; }\n\
+         .not-part { color: #e33; }\n\
+         .gear { background: #ec3; color: #000; font-weight: bold; }\n\
+         .note { color: #ec3; }\n\
+         </style>\n</head>\n<body>\n<pre>\n",
+    );
+
+    for (row, line) in schematic.grid().rows().iter().enumerate() {
+        let mut current = Mark::None;
+        for (col, &char) in line.iter().enumerate() {
+            let mark = marks[row][col];
+            if mark != current || mark == Mark::Gear {
+                if current != Mark::None {
+                    output += "</span>";
+                }
+                match mark {
+                    Mark::None => {}
+                    Mark::Part => output += "<span class=\"part\">",
+                    Mark::NotPart => output += "<span class=\"not-part\">",
+                    Mark::Gear => {
+                        let gear = gears
+                            .iter()
+                            .find(|gear| (gear.row, gear.col) == (row, col))
+                            .expect("every gear mark is a gear");
+                        let [one, two] = gear.numbers;
+                        let _ = write!(
+                            output,
+                            "<span class=\"gear\" title=\"{one} * {two} = {}\">",
+                            gear.ratio()
+                        );
+                    }
+                }
+                current = mark;
+            }
+            match char {
+                '<' => output += "&lt;",
+                '>' => output += "&gt;",
+                '&' => output += "&amp;",
+                '"' => output += "&quot;",
+                char => output.push(char),
+            }
+        }
+        if current != Mark::None {
+            output += "</span>";
+        }
+        if !notes[row].is_empty() {
+            let _ = write!(
+                output,
+                "  <span class=\"note\">{}</span>",
+                notes[row].join("; ")
+            );
+        }
+        output.push('\n');
+    }
+    output += "</pre>\n</body>\n</html>\n";
+    output
+}
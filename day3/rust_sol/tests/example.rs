@@ -0,0 +1,16 @@
+use aoc::Solution;
+use day3::Day3;
+
+const EXAMPLE: &str = include_str!("../test.txt");
+
+#[test]
+fn part_one_example() {
+    let input = Day3::parse(EXAMPLE).unwrap();
+    assert_eq!(Day3::part_one(&input), 4361);
+}
+
+#[test]
+fn part_two_example() {
+    let input = Day3::parse(EXAMPLE).unwrap();
+    assert_eq!(Day3::part_two(&input), 467835);
+}
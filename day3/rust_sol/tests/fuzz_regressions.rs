@@ -0,0 +1,32 @@
+//! Inputs the fuzz targets in `fuzz/` found crashes on.
+
+use aoc::Solution;
+use day3::Day3;
+
+#[test]
+fn rejects_bad_schematics() {
+    let inputs = [
+        // non-ASCII after a digit, then at the start of a line
+        "4ĵ",
+        "Қ5",
+        "46.\u{300}64467",
+        // digits that aren't ASCII
+        "47.¹\n....",
+        "۵...",
+        // too big for an i32
+        "75599999999999\n..............",
+        // lines of different lengths
+        "467.\n..*\n",
+    ];
+    for input in inputs {
+        assert!(Day3::parse(input).is_err(), "{input:?}");
+    }
+}
+
+#[test]
+fn sums_big_part_numbers() {
+    let schematic = Day3::parse("2147483647*2147483647").unwrap();
+
+    assert_eq!(Day3::part_one(&schematic), 2 * 2147483647);
+    assert_eq!(Day3::part_two(&schematic), 2147483647 * 2147483647);
+}
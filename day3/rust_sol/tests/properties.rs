@@ -0,0 +1,87 @@
+use aoc::{
+    generate::{self, Rng},
+    Solution,
+};
+use day3::Day3;
+
+/// Sums the part numbers and gear ratios by labelling every cell with the
+/// number it belongs to and looking at all eight neighbours of each cell.
+fn reference(input: &str) -> (i64, i64) {
+    let grid: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+    let mut numbers = vec![];
+    let mut owner = vec![vec![None; grid[0].len()]; grid.len()];
+    for (row, line) in grid.iter().enumerate() {
+        let mut col = 0;
+        while col < line.len() {
+            if !line[col].is_ascii_digit() {
+                col += 1;
+                continue;
+            }
+            let start = col;
+            while col < line.len() && line[col].is_ascii_digit() {
+                owner[row][col] = Some(numbers.len());
+                col += 1;
+            }
+            let number: String = line[start..col].iter().collect();
+            numbers.push((row, start, col, number.parse::<i64>().unwrap()));
+        }
+    }
+
+    let neighbours = |row: usize, col: usize| -> Vec<(usize, usize)> {
+        (-1..=1)
+            .flat_map(|dr| (-1..=1).map(move |dc| (dr, dc)))
+            .filter(|&offset| offset != (0, 0))
+            .filter_map(|(dr, dc)| {
+                let row = row.checked_add_signed(dr)?;
+                let col = col.checked_add_signed(dc)?;
+                grid.get(row)?.get(col).map(|_| (row, col))
+            })
+            .collect()
+    };
+    let is_symbol = |c: char| !c.is_ascii_digit() && c != '.';
+
+    let parts = numbers
+        .iter()
+        .filter(|(row, start, end, _)| {
+            (*start..*end).any(|col| {
+                neighbours(*row, col)
+                    .into_iter()
+                    .any(|(r, c)| is_symbol(grid[r][c]))
+            })
+        })
+        .map(|number| number.3)
+        .sum();
+
+    let mut ratios = 0;
+    for (row, line) in grid.iter().enumerate() {
+        for (col, cell) in line.iter().enumerate() {
+            if *cell != '*' {
+                continue;
+            }
+            let mut touching: Vec<usize> = neighbours(row, col)
+                .into_iter()
+                .filter_map(|(r, c)| owner[r][c])
+                .collect();
+            touching.sort_unstable();
+            touching.dedup();
+            if let [a, b] = touching[..] {
+                ratios += numbers[a].3 * numbers[b].3;
+            }
+        }
+    }
+    (parts, ratios)
+}
+
+#[test]
+fn matches_reference_on_random_inputs() {
+    for seed in 0..500 {
+        let mut rng = Rng::new(seed);
+        let (width, height) = (rng.range(3..=20) as usize, rng.range(1..=20) as usize);
+        let input = generate::schematic(&mut rng, width, height);
+        let schematic = Day3::parse(&input).unwrap();
+        let (parts, ratios) = reference(&input);
+
+        assert_eq!(Day3::part_one(&schematic), parts, "seed {seed}:\n{input}");
+        assert_eq!(Day3::part_two(&schematic), ratios, "seed {seed}:\n{input}");
+    }
+}
@@ -0,0 +1,17 @@
+use aoc::Solution;
+use day3::Day3;
+
+// the column bound was checked against the number of rows, so on a grid
+// wider than it's tall a number right of a gear was missed...
+#[test]
+fn wide_grid() {
+    let schematic = Day3::parse("2*3\n").unwrap();
+    assert_eq!(Day3::part_two(&schematic), 6);
+}
+
+// ...and on one taller than it's wide, a cell past the end of a row was read
+#[test]
+fn tall_grid() {
+    let schematic = Day3::parse("2*\n.3\n..\n").unwrap();
+    assert_eq!(Day3::part_two(&schematic), 6);
+}
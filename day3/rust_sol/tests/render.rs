@@ -0,0 +1,61 @@
+use aoc::Solution;
+use day3::{
+    render::{self, Mark, Style},
+    Day3,
+};
+
+const EXAMPLE: &str = include_str!("../test.txt");
+
+#[test]
+fn marks_parts_and_gears() {
+    let schematic = Day3::parse(EXAMPLE).unwrap();
+    let marks = render::marks(&schematic);
+
+    assert_eq!(marks[0][..3], [Mark::Part; 3]);
+    assert_eq!(marks[0][5..8], [Mark::NotPart; 3]);
+    assert_eq!(marks[1][3], Mark::Gear);
+    // touches only 617, so it's not a gear
+    assert_eq!(marks[4][3], Mark::None);
+
+    let ratios: Vec<i64> = render::gears(&schematic)
+        .iter()
+        .map(|gear| gear.ratio())
+        .collect();
+    assert_eq!(ratios, [16345, 451490]);
+}
+
+#[test]
+fn plain_text_has_no_colours() {
+    let schematic = Day3::parse(EXAMPLE).unwrap();
+    let plain = render::render(&schematic, Style::Plain);
+
+    assert!(!plain.contains('\x1b'));
+    assert_eq!(plain.lines().next(), Some("467..114..  not a part: 114"));
+    assert_eq!(
+        plain.lines().nth(1),
+        Some("...*......  gear at column 4: 467 * 35 = 16345")
+    );
+}
+
+#[test]
+fn ansi_colours_parts_and_gears() {
+    let schematic = Day3::parse(EXAMPLE).unwrap();
+    let ansi = render::render(&schematic, Style::Ansi);
+
+    assert_eq!(
+        ansi.lines().next(),
+        Some("\x1b[32m467\x1b[0m..\x1b[31m114\x1b[0m..")
+    );
+    assert_eq!(
+        ansi.lines().nth(1),
+        Some("...\x1b[1;30;43m*\x1b[0m......  gear at column 4: 467 * 35 = 16345")
+    );
+}
+
+#[test]
+fn html_escapes_symbols() {
+    let schematic = Day3::parse("1<2&.\n.*3..").unwrap();
+    let html = render::html(&schematic);
+
+    assert!(html.contains("<span class=\"part\">1</span>&lt;<span class=\"part\">2</span>&amp;."));
+}
@@ -0,0 +1,191 @@
+use aoc::{
+    parse::{
+        self, integer, labelled, lines, list, pair, spanned, tag, terminated, try_map, unsigned,
+        Parser,
+    },
+    ParseError, Solution,
+};
+
+/// Every scratchcard, in order.
+#[derive(Debug)]
+pub struct Table {
+    cards: Vec<Card>,
+}
+
+impl Default for Table {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Table {
+    pub fn new() -> Self {
+        Table { cards: vec![] }
+    }
+
+    /// Reads a card (`Card 1: 41 48 | 83 86 17`) and adds it to the table.
+    /// Cards have to come in order, starting from card 1.
+    pub fn add_card(&mut self, card: &str) -> Result<(), ParseError> {
+        self.push(Card::new(card)?)
+    }
+
+    fn push(&mut self, card: Card) -> Result<(), ParseError> {
+        if card.card_num != self.cards.len() + 1 {
+            return Err(format!(
+                "expected card {}, found card {}",
+                self.cards.len() + 1,
+                card.card_num
+            )
+            .into());
+        }
+        self.cards.push(card);
+        Ok(())
+    }
+
+    pub fn cards(&self) -> &[Card] {
+        &self.cards
+    }
+
+    /// Counts the copies a card ends up as, itself included: each match wins
+    /// a copy of one of the next cards, which win copies of their own.
+    ///
+    /// * `start_index`: the card's number (1-based)
+    pub fn get_copies(&self, start_index: usize) -> usize {
+        // always count at least this card
+        if self.cards[start_index - 1].matches == 0 {
+            return 1;
+        }
+        1 + self.copies_recursive(start_index)
+    }
+
+    fn copies_recursive(&self, index: usize) -> usize {
+        // base case: out of bounds or no matches
+        if index > self.cards.len() || self.cards[index - 1].matches == 0 {
+            return 0;
+        }
+
+        // add this cards matches (1 copy per match) with all of the other matched
+        // coppies
+        let matches = self.cards[index - 1].matches;
+        let copies: usize = (1..=matches)
+            .map(|offset| self.copies_recursive(index + offset))
+            .sum();
+
+        matches + copies
+    }
+}
+
+/// A scratchcard: its number and how many of its numbers are winning numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Card {
+    card_num: usize,
+    matches: usize,
+}
+
+impl Card {
+    /// Reads a card, e.g. `Card 1: 41 48 | 83 86 17`.
+    pub fn new(card_info: &str) -> Result<Self, ParseError> {
+        Ok(parse::run(card(), card_info)?)
+    }
+
+    pub fn number(&self) -> usize {
+        self.card_num
+    }
+
+    /// Gets how many of the card's numbers are winning numbers.
+    pub fn matches(&self) -> usize {
+        self.matches
+    }
+
+    /// Gets the card's points: 1 for the first match, doubled for each one
+    /// after.
+    pub fn points(&self) -> u128 {
+        match self.matches {
+            0 => 0,
+            _ => 1 << (self.matches - 1),
+        }
+    }
+}
+
+/// Reads every scratchcard, one per line.
+pub fn parse_table(contents: &str) -> Result<Table, ParseError> {
+    let mut table = Table::new();
+    for card in parse::run(lines(spanned(card())), contents)? {
+        table
+            .push(card.value)
+            .map_err(|err| format!("line {}: {err}", card.span.line))?;
+    }
+    Ok(table)
+}
+
+/// Scratchcards: points for matching numbers, then copies won by matches.
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Input = Table;
+    type Answer1 = u128;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_table(input)
+    }
+
+    fn part_one(table: &Self::Input) -> u128 {
+        table.cards.iter().map(Card::points).sum()
+    }
+
+    fn part_two(table: &Self::Input) -> usize {
+        table
+            .cards
+            .iter()
+            .map(|card| table.get_copies(card.card_num))
+            .sum()
+    }
+}
+
+/// Counts how many of my numbers are winning numbers.
+pub fn get_number_matches(winning_numbers: &[i32], my_numbers: &[i32]) -> usize {
+    my_numbers
+        .iter()
+        .filter(|num| winning_numbers.contains(num))
+        .count()
+}
+
+/// Reads the numbers after the `|` of a card.
+pub fn get_my_numbers(line: &str) -> Result<Vec<i32>, ParseError> {
+    let (_, (_, mine)) = parse::run(card_numbers(), line)?;
+    Ok(mine)
+}
+
+/// Reads the winning numbers, between the `:` and the `|` of a card.
+pub fn get_winning_nums(line: &str) -> Result<Vec<i32>, ParseError> {
+    let (_, (winning, _)) = parse::run(card_numbers(), line)?;
+    Ok(winning)
+}
+
+/// The most matches a card can have, so that any number of cards' points
+/// still add up in a `u128`.
+pub const MAX_MATCHES: usize = 64;
+
+fn card<'a>() -> impl Parser<'a, Card> {
+    try_map(
+        card_numbers(),
+        |(card_num, (winning, mine))| match get_number_matches(&winning, &mine) {
+            matches if matches > MAX_MATCHES => Err(format!(
+                "card {card_num} has {matches} matches, more than {MAX_MATCHES}"
+            )),
+            matches => Ok(Card { card_num, matches }),
+        },
+    )
+}
+
+/// Parses `Card 1: 41 48 | 83 86 17` into the card number, the winning numbers
+/// and my numbers.
+fn card_numbers<'a>() -> impl Parser<'a, (usize, (Vec<i32>, Vec<i32>))> {
+    pair(
+        terminated(labelled("Card", unsigned()), tag(":")),
+        pair(terminated(list(integer()), tag("|")), list(integer())),
+    )
+}
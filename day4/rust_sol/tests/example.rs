@@ -0,0 +1,16 @@
+use aoc::Solution;
+use day4::Day4;
+
+const EXAMPLE: &str = include_str!("../test.txt");
+
+#[test]
+fn part_one_example() {
+    let input = Day4::parse(EXAMPLE).unwrap();
+    assert_eq!(Day4::part_one(&input), 13);
+}
+
+#[test]
+fn part_two_example() {
+    let input = Day4::parse(EXAMPLE).unwrap();
+    assert_eq!(Day4::part_two(&input), 30);
+}
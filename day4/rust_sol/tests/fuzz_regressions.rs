@@ -0,0 +1,43 @@
+//! Inputs the fuzz targets in `fuzz/` found crashes on.
+
+use aoc::Solution;
+use day4::Day4;
+
+#[test]
+fn rejects_bad_cards() {
+    let inputs = [
+        ":\n",
+        "Card 1: 7 | 321324241211111",
+        "Card 1: -38484861673 | 1",
+        "Card 1: 88 32\0\0\0 17 | 81",
+        "Card 10000010844897500000099: |",
+        "Card 1 41 48 | 83 86",
+        "Card 1: 41 48 83 86",
+        // cards out of order, which part two counts copies by
+        "Card 2: 41 | 41",
+    ];
+    for input in inputs {
+        assert!(Day4::parse(input).is_err(), "{input:?}");
+    }
+}
+
+#[test]
+fn numbers_can_be_separated_by_tabs() {
+    let table = Day4::parse("Card 1: 7\t86 | 3\t86\n").unwrap();
+
+    assert_eq!(table.cards()[0].matches(), 1);
+}
+
+#[test]
+fn scores_up_to_64_matches() {
+    let card = |matches: usize| format!("Card 1: 7 |{}", " 7".repeat(matches));
+
+    let table = Day4::parse(&card(64)).unwrap();
+    assert_eq!(Day4::part_one(&table), 1 << 63);
+
+    let err = Day4::parse(&card(65)).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "line 1, column 1: card 1 has 65 matches, more than 64"
+    );
+}
@@ -0,0 +1,56 @@
+use aoc::{
+    generate::{self, Rng},
+    Solution,
+};
+use day4::Day4;
+
+/// Counts the matches on every card by splitting the lines by hand.
+fn matches(input: &str) -> Vec<usize> {
+    input
+        .lines()
+        .map(|line| {
+            let (_, numbers) = line.split_once(':').unwrap();
+            let (winning, mine) = numbers.split_once('|').unwrap();
+            let winning: Vec<&str> = winning.split_whitespace().collect();
+            mine.split_whitespace()
+                .filter(|number| winning.contains(number))
+                .count()
+        })
+        .collect()
+}
+
+/// Counts the copies of each card going forwards through the table, instead
+/// of following every copy.
+fn total_cards(matches: &[usize]) -> usize {
+    let mut copies = vec![1; matches.len()];
+    for (card, won) in matches.iter().enumerate() {
+        for next in card + 1..=(card + won).min(matches.len() - 1) {
+            copies[next] += copies[card];
+        }
+    }
+    copies.iter().sum()
+}
+
+#[test]
+fn matches_reference_on_random_inputs() {
+    for seed in 0..300 {
+        let input = generate::scratchcards(&mut Rng::new(seed), 12, 5, 8);
+        let table = Day4::parse(&input).unwrap();
+        let matches = matches(&input);
+
+        let points: u128 = matches
+            .iter()
+            .map(|matches| match matches {
+                0 => 0,
+                _ => 2u128.pow(*matches as u32 - 1),
+            })
+            .sum();
+
+        assert_eq!(Day4::part_one(&table), points, "seed {seed}:\n{input}");
+        assert_eq!(
+            Day4::part_two(&table),
+            total_cards(&matches),
+            "seed {seed}:\n{input}"
+        );
+    }
+}
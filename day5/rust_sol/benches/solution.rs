@@ -0,0 +1,14 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use day5::Day5;
+
+fn bench(c: &mut Criterion) {
+    aoc::bench::solution::<Day5>(c, env!("CARGO_MANIFEST_DIR"));
+}
+
+// part two on the full input takes seconds per run
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = bench
+}
+criterion_main!(benches);
@@ -1,6 +1,18 @@
+use aoc::{
+    parse::{
+        self, labelled, line, lines, list, pair, section, sections, spaces, spanned, tag,
+        take_while1, terminated, try_map, unsigned, Parser, Spanned,
+    },
+    trace::Trace,
+    ParseError, Solution,
+};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
 #[derive(Debug)]
 pub struct Mapping {
     source_min: u64,
+    /// one past the last source mapped
     source_max: u64,
     dest_min: u64,
 }
@@ -70,6 +82,37 @@ pub fn map_to_location(seed: u64, table: &MapTable) -> u64 {
     get_dest(humidity, &table.humidity_to_location) // location
 }
 
+/// The names of the numbers a seed is mapped through, seed first.
+const CHAIN: [&str; 8] = [
+    "seed",
+    "soil",
+    "fertilizer",
+    "water",
+    "light",
+    "temperature",
+    "humidity",
+    "location",
+];
+
+/// Follows a seed through every map, giving the number it maps to at each step
+/// in the order of `CHAIN`.
+pub fn map_chain(seed: u64, table: &MapTable) -> [u64; 8] {
+    let maps = [
+        &table.seed_to_soil,
+        &table.soil_to_fertilizer,
+        &table.fertilizer_to_water,
+        &table.water_to_light,
+        &table.light_to_temp,
+        &table.temp_to_humitidy,
+        &table.humidity_to_location,
+    ];
+    let mut chain = [seed; 8];
+    for (step, map) in maps.into_iter().enumerate() {
+        chain[step + 1] = get_dest(chain[step], map);
+    }
+    chain
+}
+
 fn map_to_seed(location: u64, table: &MapTable) -> u64 {
     let humitity = get_src(location, &table.humidity_to_location);
     let temp = get_src(humitity, &table.temp_to_humitidy);
@@ -82,7 +125,7 @@ fn map_to_seed(location: u64, table: &MapTable) -> u64 {
 
 fn get_src(dest: u64, source_maps: &[Mapping]) -> u64 {
     let search = source_maps.iter().find(|map| {
-        dest >= map.dest_min && dest <= map.dest_min + (map.source_max - map.source_min)
+        dest >= map.dest_min && dest < map.dest_min + (map.source_max - map.source_min)
     });
 
     match search {
@@ -94,7 +137,7 @@ fn get_src(dest: u64, source_maps: &[Mapping]) -> u64 {
 fn get_dest(seed: u64, source_maps: &[Mapping]) -> u64 {
     let search = source_maps
         .iter()
-        .find(|map| seed >= map.source_min && seed <= map.source_max);
+        .find(|map| seed >= map.source_min && seed < map.source_max);
 
     match search {
         Some(map) => map.dest_min + (seed - map.source_min),
@@ -102,16 +145,41 @@ fn get_dest(seed: u64, source_maps: &[Mapping]) -> u64 {
     }
 }
 
-pub fn parse_map_table(sections: &mut std::str::Split<'_, &str>) -> MapTable {
-    let seed_to_soil = parse_maps(&sections.next().unwrap());
-    let soil_to_fertilizer = parse_maps(&sections.next().unwrap());
-    let fertilizer_to_water = parse_maps(&sections.next().unwrap());
-    let water_to_light = parse_maps(&sections.next().unwrap());
-    let light_to_temp = parse_maps(&sections.next().unwrap());
-    let temp_to_humitidy = parse_maps(&sections.next().unwrap());
-    let humidity_to_location = parse_maps(&sections.next().unwrap());
+/// Reads the seven maps, from seed-to-soil to humidity-to-location, each a
+/// `<name> map:` header and its lines, with blank lines between them.
+pub fn parse_map_table(maps: &str) -> Result<MapTable, ParseError> {
+    map_table(parse::run(sections(map()), maps)?)
+}
 
-    MapTable::new(
+fn map_table(maps: Vec<Spanned<(&str, Vec<Mapping>)>>) -> Result<MapTable, ParseError> {
+    if let Some(extra) = maps.get(7) {
+        return Err(format!(
+            "line {}: `{}` map after the humidity-to-location map",
+            extra.span.line, extra.value.0
+        )
+        .into());
+    }
+    let mut maps = maps.into_iter();
+    let mut next = |name: &str| -> Result<Vec<Mapping>, ParseError> {
+        let map = maps.next().ok_or_else(|| format!("no {name} map"))?;
+        match map.value {
+            (found, mappings) if found == name => Ok(mappings),
+            (found, _) => Err(format!(
+                "line {}: expected the {name} map, found `{found}`",
+                map.span.line
+            )
+            .into()),
+        }
+    };
+    let seed_to_soil = next("seed-to-soil")?;
+    let soil_to_fertilizer = next("soil-to-fertilizer")?;
+    let fertilizer_to_water = next("fertilizer-to-water")?;
+    let water_to_light = next("water-to-light")?;
+    let light_to_temp = next("light-to-temperature")?;
+    let temp_to_humitidy = next("temperature-to-humidity")?;
+    let humidity_to_location = next("humidity-to-location")?;
+
+    Ok(MapTable::new(
         seed_to_soil,
         soil_to_fertilizer,
         fertilizer_to_water,
@@ -119,81 +187,155 @@ pub fn parse_map_table(sections: &mut std::str::Split<'_, &str>) -> MapTable {
         light_to_temp,
         temp_to_humitidy,
         humidity_to_location,
-    )
+    ))
 }
 
-fn parse_maps(mapping: &str) -> Vec<Mapping> {
-    mapping
-        .lines()
-        .skip(1)
-        .into_iter()
-        .map(|line| parse_map(line))
-        .collect()
+/// Parses a map: its name (spanning the header) and its mappings.
+fn map<'a>() -> impl Parser<'a, Spanned<(&'a str, Vec<Mapping>)>> {
+    let name = take_while1("a map name", |c: char| !c.is_whitespace());
+    let header = line(terminated(name, pair(spaces(), tag("map:"))));
+
+    spanned(pair(header, lines(mapping())))
 }
 
-fn parse_map(line: &str) -> Mapping {
-    let mut split = line.split(" ");
-    let dest_min = split
-        .next()
-        .unwrap()
-        .parse::<u64>()
-        .expect("couldn't parse dest start.");
-    let source_min = split
-        .next()
-        .unwrap()
-        .parse::<u64>()
-        .expect("couldn't parse source start.");
-    let range = split
-        .next()
-        .unwrap()
-        .parse::<u64>()
-        .expect("couldn't parse range.");
+/// Parses a line of a map: `50 98 2` maps 98 and 99 to 50 and 51.
+fn mapping<'a>() -> impl Parser<'a, Mapping> {
+    try_map(list(unsigned::<u64>()), |numbers| {
+        let [dest_min, source_min, range] = numbers[..] else {
+            return Err("a map line should be three numbers".to_string());
+        };
+        if source_min.checked_add(range).is_none() || dest_min.checked_add(range).is_none() {
+            return Err("the range goes past the biggest number".to_string());
+        }
 
-    Mapping::new(dest_min, source_min, range)
+        Ok(Mapping::new(dest_min, source_min, range))
+    })
 }
 
-pub fn parse_seed_range(seeds: &str) -> Vec<Range> {
-    let starts = seeds.split(" ").skip(1).step_by(2);
+/// Reads the seed list as pairs of a start and a length.
+pub fn parse_seed_range(seeds: &str) -> Result<Vec<Range>, ParseError> {
+    seed_ranges(&parse_seeds(seeds)?)
+}
+
+fn seed_ranges(seeds: &[u64]) -> Result<Vec<Range>, ParseError> {
+    if !seeds.len().is_multiple_of(2) {
+        return Err("the seeds should come in pairs of a start and a length".into());
+    }
 
     seeds
-        .split(" ")
-        .skip(2)
-        .step_by(2)
-        .zip(starts)
-        .map(|(len, start)| {
-            let start: u64 = start.parse().unwrap();
-            let end = start + len.parse::<u64>().unwrap();
-            Range::new(start, end)
+        .chunks(2)
+        .map(|pair| {
+            let end = pair[0]
+                .checked_add(pair[1])
+                .ok_or_else(|| format!("seed range {} {} is too big", pair[0], pair[1]))?;
+            Ok(Range::new(pair[0], end))
         })
         .collect()
 }
 
-pub fn parse_seeds(seeds: &str) -> Vec<u64> {
-    seeds
-        .split(" ")
-        .skip(1)
-        .map(|number| number.parse::<u64>().expect("Couldn't parse seed number."))
-        .collect()
+/// Reads the seed list, `seeds: 79 14 55 13`.
+pub fn parse_seeds(seeds: &str) -> Result<Vec<u64>, ParseError> {
+    Ok(parse::run(seed_list(), seeds)?)
 }
 
+fn seed_list<'a>() -> impl Parser<'a, Vec<u64>> {
+    let seeds = try_map(list(unsigned::<u64>()), |seeds| match seeds.is_empty() {
+        true => Err("there are no seeds".to_string()),
+        false => Ok(seeds),
+    });
+    line(labelled("seeds:", seeds))
+}
+
+/// Searches the locations from 0 up for the first one that maps back to a seed
+/// in one of the ranges. With the `parallel` feature the locations are
+/// searched a block at a time, every thread taking part of the block, so a
+/// small location is still found without searching far past it.
 pub fn find_min_location(seed_range: &[Range], table: &MapTable) -> Option<u64> {
-    // search through all possible locations until it finds one that matches a
-    // seed (first found is minimum)
-    for location in 0..u64::MAX {
-        let seed = map_to_seed(location, &table);
-        if valid_seed(seed, &seed_range) {
-            return Some(location);
-        }
+    let is_seed = |location: &u64| valid_seed(map_to_seed(*location, table), seed_range);
+
+    #[cfg(feature = "parallel")]
+    if aoc::parallel::enabled() {
+        const BLOCK: u64 = 1 << 16;
+        return (0..=u64::MAX / BLOCK).find_map(|block| {
+            let start = block * BLOCK;
+            (start..=start + (BLOCK - 1))
+                .into_par_iter()
+                .find_first(is_seed)
+        });
     }
-    return None;
+    (0..=u64::MAX).find(is_seed)
 }
 
 fn valid_seed(seed: u64, seed_range: &[Range]) -> bool {
-    if let Some(_) = seed_range
+    seed_range
         .iter()
-        .find(|range| seed >= range.start && seed < range.end)
-    {
-        return true;
+        .any(|range| seed >= range.start && seed < range.end)
+}
+
+pub struct Almanac {
+    seeds: Vec<u64>,
+    seed_range: Vec<Range>,
+    table: MapTable,
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Input = Almanac;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let (seeds, maps) = parse::run(pair(section(seed_list()), sections(map())), input)?;
+
+        Ok(Almanac {
+            seed_range: seed_ranges(&seeds)?,
+            seeds,
+            table: map_table(maps)?,
+        })
+    }
+
+    fn part_one(almanac: &Self::Input) -> u64 {
+        let location = |seed: &u64| map_to_location(*seed, &almanac.table);
+
+        #[cfg(feature = "parallel")]
+        if aoc::parallel::enabled() {
+            return almanac
+                .seeds
+                .par_iter()
+                .map(location)
+                .min()
+                .expect("no seeds");
+        }
+        almanac.seeds.iter().map(location).min().expect("no seeds")
+    }
+
+    fn part_two(almanac: &Self::Input) -> u64 {
+        find_min_location(&almanac.seed_range, &almanac.table).expect("No valid location found!")
+    }
+
+    fn explain(almanac: &Self::Input, trace: &mut Trace) {
+        trace.heading("part one");
+        for &seed in &almanac.seeds {
+            let chain = map_chain(seed, &almanac.table);
+            let steps: Vec<String> = CHAIN
+                .iter()
+                .zip(chain)
+                .map(|(name, number)| format!("{name} {number}"))
+                .collect();
+            trace.line(steps.join(" -> "));
+        }
+
+        // part two searches every location from 0, too many to explain
+        trace.heading("part two");
+        for range in &almanac.seed_range {
+            trace.line(format_args!(
+                "{} seeds from seed {}",
+                range.end - range.start,
+                range.start
+            ));
+        }
     }
-    false
 }
@@ -1,28 +1,15 @@
-use rust_sol::{
-    find_min_location, map_to_location, parse_map_table, parse_seed_range, parse_seeds,
-};
-use std::fs;
+use aoc::{source, trace, Solution};
+use day5::Day5;
+use std::process;
 
 fn main() {
-    let content = fs::read_to_string("input.txt").expect("Couldn't read file.");
-    let mut sections = content.split("\r\n\r\n");
-    let seed_section = sections.next().expect("no seed list");
-
-    let seeds = parse_seeds(&seed_section);
-    let seed_range = parse_seed_range(&seed_section);
-
-    let table = parse_map_table(&mut sections);
-
-    let answer_one = seeds
-        .iter()
-        .map(|seed| map_to_location(*seed, &table))
-        .min()
-        .unwrap();
-
-    println!("Answer 1: {answer_one}");
-
-    match find_min_location(&seed_range, &table) {
-        Some(answer_two) => println!("Answer 2: {answer_two}"),
-        None => println!("No valid location found!"),
-    }
+    let (contents, flags) = source::read_or_exit(Day5::DAY, env!("CARGO_MANIFEST_DIR"), &[]);
+    let almanac = Day5::parse(&contents).unwrap_or_else(|err| {
+        eprintln!("couldn't parse input: {err}");
+        process::exit(1);
+    });
+
+    println!("Answer 1: {}", Day5::part_one(&almanac));
+    println!("Answer 2: {}", Day5::part_two(&almanac));
+    trace::print_if_asked::<Day5>(&almanac, &flags);
 }
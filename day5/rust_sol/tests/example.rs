@@ -0,0 +1,16 @@
+use aoc::Solution;
+use day5::Day5;
+
+const EXAMPLE: &str = include_str!("../test.txt");
+
+#[test]
+fn part_one_example() {
+    let input = Day5::parse(EXAMPLE).unwrap();
+    assert_eq!(Day5::part_one(&input), 35);
+}
+
+#[test]
+fn part_two_example() {
+    let input = Day5::parse(EXAMPLE).unwrap();
+    assert_eq!(Day5::part_two(&input), 46);
+}
@@ -0,0 +1,23 @@
+//! Inputs the fuzz targets in `fuzz/` found crashes on.
+
+use aoc::Solution;
+use day5::Day5;
+
+#[test]
+fn rejects_bad_almanacs() {
+    let inputs = [
+        "",
+        "\r\n\r\n\r\n\r\n",
+        "seeds: 30\n\n",
+        "seeds: 0 4\n\n\n2\n",
+        "seeds: 8\n5\n\n\n",
+        "seeds: 1 1474\n\nmapz\n4 57723\n",
+        "seeds: 79 14 55\n\nseed-to-soil map:\n50 98 2\n",
+        // ranges past the biggest u64
+        "seeds: 18446744073709551615 2\n\nseed-to-soil map:\n",
+        "seeds: 1\n\nseed-to-soil map:\n0 18446744073709551615 2\n",
+    ];
+    for input in inputs {
+        assert!(Day5::parse(input).is_err(), "{input:?}");
+    }
+}
@@ -0,0 +1,91 @@
+use aoc::{
+    generate::{self, Rng},
+    Solution,
+};
+use day5::Day5;
+
+/// Reads the seeds line and the (destination, source, length) lines of every
+/// map.
+fn read(input: &str) -> (Vec<u64>, Vec<Vec<[u64; 3]>>) {
+    let mut sections = input.split("\n\n");
+    let seeds = sections
+        .next()
+        .unwrap()
+        .split_whitespace()
+        .skip(1)
+        .map(|number| number.parse().unwrap())
+        .collect();
+    let maps = sections
+        .map(|section| {
+            section
+                .lines()
+                .skip(1)
+                .map(|line| {
+                    let numbers: Vec<u64> = line
+                        .split_whitespace()
+                        .map(|number| number.parse().unwrap())
+                        .collect();
+                    [numbers[0], numbers[1], numbers[2]]
+                })
+                .collect()
+        })
+        .collect();
+    (seeds, maps)
+}
+
+/// Follows a seed through every map, where a line moves `source..source + len`.
+fn location(seed: u64, maps: &[Vec<[u64; 3]>]) -> u64 {
+    maps.iter().fold(seed, |value, map| {
+        map.iter()
+            .find(|[_, source, len]| (*source..source + len).contains(&value))
+            .map_or(value, |[dest, source, _]| dest + (value - source))
+    })
+}
+
+#[test]
+fn matches_reference_on_random_inputs() {
+    for seed in 0..300 {
+        let input = generate::almanac(&mut Rng::new(seed), 3, 200, 6);
+        let almanac = Day5::parse(&input).unwrap();
+        let (seeds, maps) = read(&input);
+
+        let nearest = seeds.iter().map(|seed| location(*seed, &maps)).min();
+        // every seed in every range, one at a time
+        let nearest_in_ranges = seeds
+            .chunks(2)
+            .flat_map(|pair| pair[0]..pair[0] + pair[1])
+            .map(|seed| location(seed, &maps))
+            .min();
+
+        assert_eq!(
+            Some(Day5::part_one(&almanac)),
+            nearest,
+            "seed {seed}:\n{input}"
+        );
+        assert_eq!(
+            Some(Day5::part_two(&almanac)),
+            nearest_in_ranges,
+            "seed {seed}:\n{input}"
+        );
+    }
+}
+
+// the parallel search goes through the locations in blocks, so the nearest
+// location has to be found wherever it is in a block
+#[test]
+fn finds_locations_past_the_first_block() {
+    for start in [65_535, 65_536, 65_537, 200_000] {
+        let input = format!(
+            "seeds: {start} 3\n\n\
+             seed-to-soil map:\n0 500000 1\n\n\
+             soil-to-fertilizer map:\n\n\
+             fertilizer-to-water map:\n\n\
+             water-to-light map:\n\n\
+             light-to-temperature map:\n\n\
+             temperature-to-humidity map:\n\n\
+             humidity-to-location map:\n"
+        );
+        let almanac = Day5::parse(&input).unwrap();
+        assert_eq!(Day5::part_two(&almanac), start, "seeds from {start}");
+    }
+}
@@ -0,0 +1,39 @@
+use aoc::Solution;
+use day5::Day5;
+
+const EXAMPLE: &str = include_str!("../test.txt");
+
+// sections were only split on CRLF blank lines, so an input with plain LF
+// line endings (like the checked-in ones) was read as a single section
+#[test]
+fn lf_line_endings() {
+    let almanac = Day5::parse(EXAMPLE).unwrap();
+    assert_eq!(Day5::part_one(&almanac), 35);
+    assert_eq!(Day5::part_two(&almanac), 46);
+}
+
+#[test]
+fn crlf_line_endings() {
+    let almanac = Day5::parse(&EXAMPLE.replace('\n', "\r\n")).unwrap();
+    assert_eq!(Day5::part_one(&almanac), 35);
+    assert_eq!(Day5::part_two(&almanac), 46);
+}
+
+// a map covers `length` values from its start, so the value right after its
+// range isn't mapped, either forwards (part one) or backwards (part two)
+#[test]
+fn map_ranges_end_before_start_plus_length() {
+    let almanac = Day5::parse(
+        "seeds: 25 30\n\n\
+         seed-to-soil map:\n5 20 5\n\n\
+         soil-to-fertilizer map:\n\n\
+         fertilizer-to-water map:\n\n\
+         water-to-light map:\n\n\
+         light-to-temperature map:\n\n\
+         temperature-to-humidity map:\n\n\
+         humidity-to-location map:\n",
+    )
+    .unwrap();
+    assert_eq!(Day5::part_one(&almanac), 25);
+    assert_eq!(Day5::part_two(&almanac), 25);
+}
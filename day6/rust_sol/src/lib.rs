@@ -1,4 +1,9 @@
-use std::str::Lines;
+use aoc::{
+    parse::{self, digits, labelled, line, list, pair, try_map, unsigned, Parser},
+    ParseError, Solution,
+};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
 #[derive(Debug)]
 pub struct Race {
@@ -15,50 +20,95 @@ impl Race {
         (0..=self.time)
             .filter(|hold_time| {
                 let time_to_run = self.time - hold_time;
-                hold_time * time_to_run > self.distance // hold time is also speed
+                // hold time is also speed, and a distance too big for a u64
+                // beats any record
+                hold_time
+                    .checked_mul(time_to_run)
+                    .is_none_or(|distance| distance > self.distance)
             })
             .count() as u64
     }
 }
 
-pub fn parse_input(input: &str) -> Vec<Race> {
-    let mut lines = input.lines();
-    let times = parse_line(&mut lines);
-    let distance = parse_line(&mut lines);
+/// Reads the races, with their times on the first line and their record
+/// distances on the second.
+pub fn parse_input(input: &str) -> Result<Vec<Race>, ParseError> {
+    let numbers = || list(unsigned::<u64>());
+    let (times, distance) = parse::run(races(numbers(), numbers()), input)?;
+    if times.len() != distance.len() {
+        return Err(format!(
+            "there are {} times but {} distances",
+            times.len(),
+            distance.len()
+        )
+        .into());
+    }
 
-    times
+    Ok(times
         .iter()
         .zip(distance)
         .map(|(time, distance)| Race::new(*time, distance))
-        .collect()
+        .collect())
 }
 
-fn parse_line(lines: &mut Lines<'_>) -> Vec<u64> {
-    lines
-        .next()
-        .expect("no next line in input")
-        .split_whitespace()
-        .skip(1)
-        .map(|ele| ele.parse().expect("encountered non-number in input"))
-        .collect()
-}
+/// Reads the input as one race, ignoring the spaces between the digits.
+pub fn parse_one_race(content: &str) -> Result<Race, ParseError> {
+    let (time, distance) = parse::run(races(kerned(), kerned()), content)?;
 
-pub fn parse_one_race(content: &str) -> Race {
-    let mut lines = content.lines();
-    let time = parse_line_single(&mut lines);
-    let distance = parse_line_single(&mut lines);
+    Ok(Race::new(time, distance))
+}
 
-    Race::new(
-        time.parse().expect("Couldn't parse value."),
-        distance.parse().expect("Coundn't parse value."),
+/// Parses the `Time:` line and the `Distance:` line.
+fn races<'a, T>(
+    times: impl Parser<'a, T>,
+    distances: impl Parser<'a, T>,
+) -> impl Parser<'a, (T, T)> {
+    pair(
+        line(labelled("Time:", times)),
+        line(labelled("Distance:", distances)),
     )
 }
 
-fn parse_line_single(lines: &mut Lines<'_>) -> String {
-    lines
-        .next()
-        .expect("no next line in input")
-        .split_whitespace()
-        .skip(1)
-        .collect()
+/// Parses digits with spaces between them as one number.
+fn kerned<'a>() -> impl Parser<'a, u64> {
+    try_map(list(digits()), |digits| {
+        let digits = digits.concat();
+        digits
+            .parse()
+            .map_err(|err| format!("bad number `{digits}`: {err}"))
+    })
+}
+
+pub struct Races {
+    races: Vec<Race>,
+    combined: Race,
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Input = Races;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(Races {
+            races: parse_input(input)?,
+            combined: parse_one_race(input)?,
+        })
+    }
+
+    fn part_one(races: &Self::Input) -> u64 {
+        #[cfg(feature = "parallel")]
+        if aoc::parallel::enabled() {
+            return races.races.par_iter().map(Race::ways_to_win).product();
+        }
+        races.races.iter().map(Race::ways_to_win).product()
+    }
+
+    fn part_two(races: &Self::Input) -> u64 {
+        races.combined.ways_to_win()
+    }
 }
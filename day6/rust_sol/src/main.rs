@@ -1,14 +1,15 @@
-use rust_sol::{parse_input, parse_one_race};
-use std::fs;
+use aoc::{source, trace, Solution};
+use day6::Day6;
+use std::process;
 
 fn main() {
-    let content = fs::read_to_string("input.txt").expect("Couldn't read file.");
-    let races = parse_input(&content);
-    let combo_race = parse_one_race(&content);
+    let (contents, flags) = source::read_or_exit(Day6::DAY, env!("CARGO_MANIFEST_DIR"), &[]);
+    let races = Day6::parse(&contents).unwrap_or_else(|err| {
+        eprintln!("couldn't parse input: {err}");
+        process::exit(1);
+    });
 
-    let answer_one: u64 = races.iter().map(|race| race.ways_to_win()).product();
-    let answer_two = combo_race.ways_to_win();
-
-    println!("Answer 1: {answer_one}");
-    println!("Answer 2: {answer_two}");
+    println!("Answer 1: {}", Day6::part_one(&races));
+    println!("Answer 2: {}", Day6::part_two(&races));
+    trace::print_if_asked::<Day6>(&races, &flags);
 }
@@ -0,0 +1,16 @@
+use aoc::Solution;
+use day6::Day6;
+
+const EXAMPLE: &str = include_str!("../test.txt");
+
+#[test]
+fn part_one_example() {
+    let input = Day6::parse(EXAMPLE).unwrap();
+    assert_eq!(Day6::part_one(&input), 288);
+}
+
+#[test]
+fn part_two_example() {
+    let input = Day6::parse(EXAMPLE).unwrap();
+    assert_eq!(Day6::part_two(&input), 71503);
+}
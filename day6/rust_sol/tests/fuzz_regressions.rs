@@ -0,0 +1,21 @@
+//! Inputs the fuzz targets in `fuzz/` found crashes on.
+
+use aoc::Solution;
+use day6::Day6;
+
+#[test]
+fn rejects_bad_races() {
+    let inputs = [
+        "",
+        "T\0ii",
+        "Time: \u{b}\u{2}",
+        "Time: 18446744073709551616\nDistance: 1",
+        "Time: 0\nDistance:",
+        "Time: 7 15\nDistance: 9",
+        // fine as races, too big as one race
+        "Time: 9999999999 9999999999\nDistance: 1 1",
+    ];
+    for input in inputs {
+        assert!(Day6::parse(input).is_err(), "{input:?}");
+    }
+}
@@ -0,0 +1,65 @@
+use aoc::{
+    generate::{self, Rng},
+    Solution,
+};
+use day6::Day6;
+
+/// Counts the ways to win with the quadratic formula: holding for `h` wins
+/// when `h * (time - h) > distance`.
+fn ways_to_win(time: u64, distance: u64) -> u64 {
+    let beats = |hold: u64| hold * (time - hold) > distance;
+    let Some(discriminant) = (time * time).checked_sub(4 * distance) else {
+        return 0;
+    };
+    let root = (discriminant as f64).sqrt();
+
+    // the float roots can be off by one either way, so nudge them onto the
+    // first and last winning hold times
+    let mut low = ((time as f64 - root) / 2.0).floor() as u64;
+    while low <= time / 2 && !beats(low) {
+        low += 1;
+    }
+    while low > 0 && beats(low - 1) {
+        low -= 1;
+    }
+    match beats(low) {
+        true => time - 2 * low + 1,
+        false => 0,
+    }
+}
+
+/// Reads the numbers of a line, and all of them written as one number.
+fn read(line: &str) -> (Vec<u64>, u64) {
+    let numbers: Vec<&str> = line.split_whitespace().skip(1).collect();
+    (
+        numbers
+            .iter()
+            .map(|number| number.parse().unwrap())
+            .collect(),
+        numbers.concat().parse().unwrap(),
+    )
+}
+
+#[test]
+fn matches_reference_on_random_inputs() {
+    for seed in 0..300 {
+        let input = generate::races(&mut Rng::new(seed), 3, 60);
+        let races = Day6::parse(&input).unwrap();
+        let mut lines = input.lines();
+        let (times, time) = read(lines.next().unwrap());
+        let (distances, distance) = read(lines.next().unwrap());
+
+        let product: u64 = times
+            .iter()
+            .zip(&distances)
+            .map(|(time, distance)| ways_to_win(*time, *distance))
+            .product();
+
+        assert_eq!(Day6::part_one(&races), product, "seed {seed}:\n{input}");
+        assert_eq!(
+            Day6::part_two(&races),
+            ways_to_win(time, distance),
+            "seed {seed}:\n{input}"
+        );
+    }
+}
@@ -1,125 +1,249 @@
+use aoc::{
+    parse::{self, delimited, lines, pair, preceded, spaces, take_while1, try_map, unsigned},
+    trace::Trace,
+    ParseError, Solution,
+};
 use std::collections::HashMap;
 
+pub mod poker;
+mod ranking;
+mod ruleset;
+
+pub use ranking::{RankedHand, Ranking};
+pub use ruleset::{Ruleset, RulesetError, TieBreak};
+
 #[derive(Debug, PartialEq, PartialOrd, Eq, Ord)]
 pub struct Hand {
-    kind: u8,
+    kind: HandKind,
+    ranks: Vec<(u8, Card)>,
     cards: Vec<Card>,
     pub bid: u32,
 }
 
 impl Hand {
-    /// Constructs a hand of cards
+    /// Constructs a hand of cards, ranked under the given rules
     ///
     /// * `cards`: the Cards in the hand
     /// * `bid`: the bid of the hand
-    pub fn new(cards: Vec<Card>, bid: u32) -> Self {
-        let kind = Hand::get_kind(&cards);
+    /// * `rules`: decides which cards are wild and how ties are broken
+    pub fn new(cards: Vec<Card>, bid: u32, rules: &Ruleset) -> Self {
+        let kind = HandKind::from_cards(&cards, rules.wild());
+        let ranks = rules.tie_break_ranks(&cards);
+
+        Hand {
+            kind,
+            ranks,
+            cards,
+            bid,
+        }
+    }
 
-        Hand { cards, bid, kind }
+    /// Gets the kind of hand (e.g. 3 of a kind, 2 pair)
+    pub fn kind(&self) -> HandKind {
+        self.kind
     }
+}
+
+/// The kinds of hand, weakest first. Straights and flushes only come up when
+/// playing standard poker.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub enum HandKind {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    Straight,
+    Flush,
+    FullHouse,
+    FourOfAKind,
+    StraightFlush,
+    FiveOfAKind,
+}
 
-    /// Gets the kind of card (e.g. 3 of a kind, 2 pair)
+impl HandKind {
+    /// Classifies a hand, treating every card in `wild` as a joker.
     ///
-    /// * `cards`: a vec of Card enums
-    fn get_kind(cards: &[Card]) -> u8 {
+    /// * `cards`: a slice of Card enums
+    /// * `wild`: the cards that can stand in for any other card
+    pub fn from_cards(cards: &[Card], wild: &[Card]) -> Self {
         let mut found: HashMap<Card, u8> = HashMap::new();
         let mut wilds = 0;
-        cards.iter().for_each(|card| {
-            if *card == Card::Wild {
-                wilds += 1;
-            } else if !found.contains_key(card) {
-                found.insert(card.clone(), 1);
-            } else {
-                *found.get_mut(card).unwrap() += 1;
-            }
+        cards.iter().for_each(|card| match wild.contains(card) {
+            true => wilds += 1,
+            false => *found.entry(*card).or_insert(0) += 1,
         });
 
-        let max = *found.values().max().unwrap_or(&0);
-        let kind = match max {
-            5 | 4 => max + 1,
-            3 if found.values().len() == 2 && wilds == 0 => max + 1,
-            3 => max,
-            2 if found.values().len() == 3 && wilds == 0 => max, // two pair
-            2 if found.values().len() == 2 && wilds == 1 => max, // two pair
-            2 => max - 1,
-            _ => 0,
-        };
-        if wilds == 0 {
-            return kind;
+        let mut signature: Vec<u8> = found.into_values().collect();
+        signature.sort_unstable_by(|a, b| b.cmp(a));
+
+        // jokers always do best by joining the largest group
+        match signature.first_mut() {
+            Some(largest) => *largest += wilds,
+            None => signature.push(wilds),
         }
-        Self::get_wilds(kind, wilds)
+        Self::from_signature(&signature)
     }
 
-    fn get_wilds(kind: u8, wilds: u8) -> u8 {
-        match (kind, wilds) {
-            (6, _) => panic!("too many cards!"), // 5 of a kind and some jacks
-            (4, _) => panic!("full house and some jacks!"),
-            (5, 1) | (0, 1) => kind + wilds,
-            (3, _) | (2, 1) | (1, 1) | (0, 2) => kind + wilds + 1,
-            (1, 2) | (1, 3) | (0, 3) => kind + wilds + 2,
-            (0, 4) | (0, 5) => 6, // 0 pairs and 4 or 5 jacks (five of a kind)
-            _ => 0,
+    /// Gets the kind from card counts sorted largest first (e.g. `[3, 2]` is a
+    /// full house).
+    ///
+    /// * `signature`: card counts in descending order
+    pub fn from_signature(signature: &[u8]) -> Self {
+        match signature {
+            [n, ..] if *n >= 5 => HandKind::FiveOfAKind,
+            [4, ..] => HandKind::FourOfAKind,
+            [3, 2, ..] => HandKind::FullHouse,
+            [3, ..] => HandKind::ThreeOfAKind,
+            [2, 2, ..] => HandKind::TwoPair,
+            [2, ..] => HandKind::OnePair,
+            _ => HandKind::HighCard,
         }
     }
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
 pub enum Card {
-    Wild, Two, Three, Four, Five, Six,
-    Seven, Eight, Nine, Ten, J, Q, K, A,
+    Two, Three, Four, Five, Six, Seven,
+    Eight, Nine, Ten, J, Q, K, A,
+}
+
+impl Card {
+    /// Gets the card for a label character (e.g. 'T' for Ten)
+    pub fn from_label(label: char) -> Option<Card> {
+        match label {
+            '2' => Some(Card::Two),
+            '3' => Some(Card::Three),
+            '4' => Some(Card::Four),
+            '5' => Some(Card::Five),
+            '6' => Some(Card::Six),
+            '7' => Some(Card::Seven),
+            '8' => Some(Card::Eight),
+            '9' => Some(Card::Nine),
+            'T' => Some(Card::Ten),
+            'J' => Some(Card::J),
+            'Q' => Some(Card::Q),
+            'K' => Some(Card::K),
+            'A' => Some(Card::A),
+            _ => None,
+        }
+    }
+
+    /// Gets the label character used for the card in the input
+    pub fn label(&self) -> char {
+        match self {
+            Card::Two => '2',
+            Card::Three => '3',
+            Card::Four => '4',
+            Card::Five => '5',
+            Card::Six => '6',
+            Card::Seven => '7',
+            Card::Eight => '8',
+            Card::Nine => '9',
+            Card::Ten => 'T',
+            Card::J => 'J',
+            Card::Q => 'Q',
+            Card::K => 'K',
+            Card::A => 'A',
+        }
+    }
 }
 
-/// Returns the total winnings in from a sorted list of hands.
+/// Returns the total winnings in from a sorted list of hands, or None if they
+/// don't fit in a u64. Use `Ranking` if the hands aren't sorted yet.
 ///
 /// * `hands`: A sorted list of hands
-pub fn get_total_winings(hands: &[Hand]) -> u32 {
+pub fn get_total_winings(hands: &[Hand]) -> Option<u64> {
     hands
         .iter()
         .enumerate()
-        .map(|(index, hand)| (index + 1) as u32 * hand.bid)
-        .sum()
+        .map(|(index, hand)| (index as u64 + 1).checked_mul(hand.bid as u64))
+        .try_fold(0u64, |total, winnings| total.checked_add(winnings?))
 }
 
 /// parses hands from input
 ///
 /// * `contents`: input string
-pub fn parse_hands(contents: &str, wild_cards: bool) -> Vec<Hand> {
-    contents
-        .lines()
-        .map(|line| {
-            let mut split = line.split_whitespace();
-            let cards = split.next().expect("No cards in hand.");
-            let bid: u32 = split
-                .next()
-                .expect("No bid in hand.")
-                .parse()
-                .expect("couldn't parse bid.");
-            let cards = parse_cards(cards, wild_cards);
-
-            Hand::new(cards, bid)
-        })
-        .collect()
+/// * `rules`: the rules to rank the hands under
+pub fn parse_hands(contents: &str, rules: &Ruleset) -> Result<Vec<Hand>, ParseError> {
+    let cards = try_map(take_while1("cards", |c| !c.is_whitespace()), |cards| {
+        parse_cards(cards, rules)
+    });
+    let hand = pair(cards, preceded(spaces(), unsigned::<u32>()));
+    let hands = lines(delimited(spaces(), hand, spaces()));
+
+    Ok(parse::run(hands, contents)?
+        .into_iter()
+        .map(|(cards, bid)| Hand::new(cards, bid, rules))
+        .collect())
 }
 
-fn parse_cards(card_string: &str, wild_cards: bool) -> Vec<Card> {
-    card_string
+fn parse_cards(card_string: &str, rules: &Ruleset) -> Result<Vec<Card>, String> {
+    let cards = card_string
         .chars()
-        .map(|card| match card {
-            '2' => Card::Two,
-            '3' => Card::Three,
-            '4' => Card::Four,
-            '5' => Card::Five,
-            '6' => Card::Six,
-            '7' => Card::Seven,
-            '8' => Card::Eight,
-            '9' => Card::Nine,
-            'T' => Card::Ten,
-            'J' if wild_cards => Card::Wild,
-            'J' => Card::J,
-            'Q' => Card::Q,
-            'K' => Card::K,
-            'A' => Card::A,
-            _ => panic!("unexpected card type found."),
+        .map(|label| match Card::from_label(label) {
+            Some(card) if rules.rank(card).is_some() => Ok(card),
+            _ => Err(format!("unexpected card `{label}`")),
         })
-        .collect()
+        .collect::<Result<Vec<Card>, String>>()?;
+
+    if cards.len() != rules.hand_size() {
+        return Err(format!(
+            "expected {} cards in hand, found {}",
+            rules.hand_size(),
+            cards.len()
+        ));
+    }
+    Ok(cards)
+}
+
+/// The same hands ranked under both sets of rules
+pub struct Rankings {
+    pub camel_cards: Ranking,
+    pub jokers: Ranking,
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    type Input = Rankings;
+    // a u128 so no number of hands can overflow the winnings
+    type Answer1 = u128;
+    type Answer2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        // wild cards not considered for part 1
+        Ok(Rankings {
+            camel_cards: Ranking::new(parse_hands(input, &Ruleset::camel_cards())?),
+            jokers: Ranking::new(parse_hands(input, &Ruleset::jokers())?),
+        })
+    }
+
+    fn part_one(rankings: &Self::Input) -> u128 {
+        rankings.camel_cards.total_winnings_wide()
+    }
+
+    fn part_two(rankings: &Self::Input) -> u128 {
+        rankings.jokers.total_winnings_wide()
+    }
+
+    fn explain(rankings: &Self::Input, trace: &mut Trace) {
+        for (heading, ranking) in [
+            ("part one", &rankings.camel_cards),
+            ("part two, J is a joker", &rankings.jokers),
+        ] {
+            trace.heading(heading);
+            for row in ranking.explain() {
+                let tie_breaker = match row.tie_breaker {
+                    Some(card) => format!(", beats the hand below on {}", card.label()),
+                    None => String::new(),
+                };
+                trace.line(format_args!(
+                    "rank {}: {} is {:?}{tie_breaker}, bid {}",
+                    row.rank, row.cards, row.kind, row.bid
+                ));
+            }
+        }
+    }
 }
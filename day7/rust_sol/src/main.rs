@@ -1,21 +1,44 @@
-use std::fs;
+use std::process;
 
-use rust_sol::{parse_hands, get_total_winings};
+use aoc::{source, trace, Solution};
+use day7::{poker, Day7, Ranking};
 
 fn main() {
-    let contents = fs::read_to_string("input.txt").expect("Couldn't read input.");
+    let (contents, flags) = source::read_or_exit(
+        Day7::DAY,
+        env!("CARGO_MANIFEST_DIR"),
+        &["--poker", "--table"],
+    );
+    let show_table = flags.iter().any(|flag| flag == "--table");
 
-    // wild cards not considered for part 1
-    let mut hands = parse_hands(&contents, false);
-    let mut wild_hands = parse_hands(&contents, true);
+    // standard poker with suited cards (e.g. `AhKdQcJsTh 100`) instead
+    if flags.iter().any(|flag| flag == "--poker") {
+        if flags.iter().any(|flag| flag == "--explain") {
+            eprintln!("--explain only explains camel cards, not --poker");
+            process::exit(1);
+        }
+        let hands = poker::parse_poker_hands(&contents).unwrap_or_else(|err| {
+            eprintln!("couldn't parse poker hands: {err}");
+            process::exit(1);
+        });
+        let ranking = Ranking::new(hands);
+        if show_table {
+            println!("{ranking}\n");
+        }
+        println!("Total winnings: {}", ranking.total_winnings_wide());
+        return;
+    }
 
-    // sort the two lists before calculating winnings
-    hands.sort();
-    wild_hands.sort();
-    
-    let answer_one = get_total_winings(&hands);
-    let answer_two = get_total_winings(&wild_hands);
+    let rankings = Day7::parse(&contents).unwrap_or_else(|err| {
+        eprintln!("couldn't parse input: {err}");
+        process::exit(1);
+    });
 
-    println!("Answer 1: {answer_one}");
-    println!("Answer 2: {answer_two}");
+    if show_table {
+        println!("{}\n\n{}\n", rankings.camel_cards, rankings.jokers);
+    }
+
+    println!("Answer 1: {}", Day7::part_one(&rankings));
+    println!("Answer 2: {}", Day7::part_two(&rankings));
+    trace::print_if_asked::<Day7>(&rankings, &flags);
 }
@@ -0,0 +1,132 @@
+//! Standard poker hands, where every card has a suit (e.g. `AhKdQcJsTh 100`).
+
+use aoc::parse::{
+    self, char_if, delimited, lines, many, pair, preceded, spaces, spanned, try_map, unsigned,
+    ErrorKind, Found, Input, Parser,
+};
+
+use crate::{Card, Hand, HandKind, Ruleset, TieBreak};
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub enum Suit {
+    Clubs,
+    Diamonds,
+    Hearts,
+    Spades,
+}
+
+impl Suit {
+    /// Gets the suit for a label character (c, d, h or s)
+    pub fn from_label(label: char) -> Option<Suit> {
+        match label {
+            'c' => Some(Suit::Clubs),
+            'd' => Some(Suit::Diamonds),
+            'h' => Some(Suit::Hearts),
+            's' => Some(Suit::Spades),
+            _ => None,
+        }
+    }
+
+    /// Gets the label character used for the suit in the input
+    pub fn label(&self) -> char {
+        match self {
+            Suit::Clubs => 'c',
+            Suit::Diamonds => 'd',
+            Suit::Hearts => 'h',
+            Suit::Spades => 's',
+        }
+    }
+}
+
+/// Gets the kind of a 5 card poker hand and the card strengths to compare when
+/// two hands are the same kind (pairs before kickers, and only the top card of
+/// a straight).
+///
+/// * `cards`: the cards in the hand with their suits
+pub fn evaluate(cards: &[(Card, Suit)]) -> (HandKind, Vec<(u8, Card)>) {
+    let rules = Ruleset::camel_cards().with_tie_break(TieBreak::Groups);
+    let faces: Vec<Card> = cards.iter().map(|(card, _)| *card).collect();
+
+    let kind = HandKind::from_cards(&faces, rules.wild());
+    let ranks = rules.tie_break_ranks(&faces);
+    let flush = cards.iter().all(|(_, suit)| *suit == cards[0].1);
+
+    match (straight_high_card(&ranks), flush) {
+        (Some(high), true) => (HandKind::StraightFlush, vec![high]),
+        (Some(high), false) => (HandKind::Straight, vec![high]),
+        (None, true) => (HandKind::Flush, ranks),
+        (None, false) => (kind, ranks),
+    }
+}
+
+/// Gets the top card (and its strength) if the hand is a straight. Aces can
+/// also play low (A2345), where the five is the top card.
+///
+/// * `ranks`: card strengths sorted strongest first
+fn straight_high_card(ranks: &[(u8, Card)]) -> Option<(u8, Card)> {
+    let strengths: Vec<u8> = ranks.iter().map(|(rank, _)| *rank).collect();
+
+    if strengths.len() != 5 || strengths.windows(2).any(|pair| pair[0] == pair[1]) {
+        return None;
+    }
+    if strengths[0] - strengths[4] == 4 {
+        return Some(ranks[0]);
+    }
+    if ranks[0].1 == Card::A && ranks[1].1 == Card::Five && strengths[1] - strengths[4] == 3 {
+        return Some(ranks[1]);
+    }
+    None
+}
+
+/// Parses suited hands, one per line with a bid (e.g. `AhKdQcJsTh 100`).
+/// Every hand has 5 different cards.
+///
+/// * `contents`: input string
+pub fn parse_poker_hands(contents: &str) -> Result<Vec<Hand>, parse::Error> {
+    let hand = pair(suited_cards(), preceded(spaces(), unsigned::<u32>()));
+    let hands = lines(delimited(spaces(), hand, spaces()));
+
+    Ok(parse::run(hands, contents)?
+        .into_iter()
+        .map(|(cards, bid)| {
+            let (kind, ranks) = evaluate(&cards);
+            Hand {
+                kind,
+                ranks,
+                cards: cards.into_iter().map(|(card, _)| card).collect(),
+                bid,
+            }
+        })
+        .collect())
+}
+
+/// Parses the cards of a hand, each a label followed by a suit (e.g. `Kh`).
+fn suited_cards<'a>() -> impl Parser<'a, Vec<(Card, Suit)>> {
+    let label = |what: &'static str| char_if(what, |c| !c.is_whitespace());
+    let card = try_map(label("a card"), |label| {
+        Card::from_label(label).ok_or_else(|| format!("unexpected card `{label}`"))
+    });
+    let suit = try_map(label("a suit"), |label| {
+        Suit::from_label(label).ok_or_else(|| format!("unexpected suit `{label}`"))
+    });
+    let cards = try_map(many(spanned(pair(card, suit))), |cards| match cards.len() {
+        5 => Ok(cards),
+        len => Err(format!("expected 5 cards in hand, found {len}")),
+    });
+
+    move |input: Input<'a>| {
+        let (cards, rest) = cards.parse(input)?;
+        // the error points at the second copy of the card, not the whole hand
+        for (index, card) in cards.iter().enumerate() {
+            if cards[..index].iter().any(|other| other.value == card.value) {
+                let (label, suit) = (card.value.0.label(), card.value.1.label());
+                return Err(parse::Error {
+                    span: card.span,
+                    kind: ErrorKind::Invalid(format!("`{label}{suit}` dealt twice in hand")),
+                    found: Found::Text(format!("{label}{suit}")),
+                });
+            }
+        }
+        Ok((cards.into_iter().map(|card| card.value).collect(), rest))
+    }
+}
@@ -0,0 +1,125 @@
+use std::fmt;
+
+use crate::{Card, Hand, HandKind};
+
+/// A set of hands sorted from weakest to strongest.
+#[derive(Debug)]
+pub struct Ranking {
+    hands: Vec<Hand>,
+}
+
+/// One row of a ranking explanation.
+#[derive(Debug, PartialEq, Eq)]
+pub struct RankedHand {
+    pub rank: u64,
+    pub kind: HandKind,
+    pub cards: String,
+    pub bid: u32,
+    /// The card that put this hand above the next weakest hand, if both are
+    /// the same kind
+    pub tie_breaker: Option<Card>,
+    /// `rank * bid`, or None if it doesn't fit in a u64
+    pub winnings: Option<u64>,
+}
+
+impl Ranking {
+    /// Ranks a set of hands
+    ///
+    /// * `hands`: the hands in any order
+    pub fn new(mut hands: Vec<Hand>) -> Self {
+        hands.sort();
+        Ranking { hands }
+    }
+
+    /// Gets the hands, weakest first
+    pub fn hands(&self) -> &[Hand] {
+        &self.hands
+    }
+
+    /// Returns the total winnings, or None if they don't fit in a u64.
+    pub fn total_winnings(&self) -> Option<u64> {
+        self.hands
+            .iter()
+            .enumerate()
+            .try_fold(0u64, |total, (index, hand)| {
+                total.checked_add(winnings(index, hand)?)
+            })
+    }
+
+    /// Returns the total winnings in a u128, which can't overflow for any
+    /// number of hands that fits in memory.
+    pub fn total_winnings_wide(&self) -> u128 {
+        self.hands
+            .iter()
+            .enumerate()
+            .map(|(index, hand)| (index as u128 + 1) * hand.bid as u128)
+            .sum()
+    }
+
+    /// Explains how every hand was ranked, weakest first.
+    pub fn explain(&self) -> Vec<RankedHand> {
+        self.hands
+            .iter()
+            .enumerate()
+            .map(|(index, hand)| {
+                let below = index.checked_sub(1).map(|below| &self.hands[below]);
+
+                RankedHand {
+                    rank: index as u64 + 1,
+                    kind: hand.kind,
+                    cards: hand.cards.iter().map(|card| card.label()).collect(),
+                    bid: hand.bid,
+                    tie_breaker: below.and_then(|below| tie_breaker(hand, below)),
+                    winnings: winnings(index, hand),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Gets the winnings for the hand at `index` in a sorted list.
+fn winnings(index: usize, hand: &Hand) -> Option<u64> {
+    (index as u64).checked_add(1)?.checked_mul(hand.bid as u64)
+}
+
+/// Gets the first card in tie breaking order where `hand` beats `below`.
+fn tie_breaker(hand: &Hand, below: &Hand) -> Option<Card> {
+    if hand.kind != below.kind {
+        return None;
+    }
+    hand.ranks
+        .iter()
+        .zip(&below.ranks)
+        .find(|(this, other)| this != other)
+        .map(|((_, card), _)| *card)
+}
+
+impl fmt::Display for Ranking {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:>6}  {:<8}  {:<15}  {:<11}  {:>10}  {:>20}",
+            "rank", "hand", "kind", "tie breaker", "bid", "winnings"
+        )?;
+        for row in self.explain() {
+            let tie_breaker = row.tie_breaker.map_or('-', |card| card.label());
+            let winnings = row
+                .winnings
+                .map_or("overflow".to_string(), |winnings| winnings.to_string());
+            writeln!(
+                f,
+                "{:>6}  {:<8}  {:<15}  {:<11}  {:>10}  {:>20}",
+                row.rank,
+                row.cards,
+                format!("{:?}", row.kind),
+                tie_breaker,
+                row.bid,
+                winnings
+            )?;
+        }
+        match self.total_winnings() {
+            Some(total) => write!(f, "total winnings: {total}"),
+            None => write!(f, "total winnings: {}", self.total_winnings_wide()),
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use std::fmt;
+
+use crate::Card;
+
+/// Why a ruleset couldn't be made.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RulesetError {
+    /// A label that isn't a card
+    UnknownCard(char),
+    /// A card ranked more than once
+    RankedTwice(char),
+}
+
+impl fmt::Display for RulesetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RulesetError::UnknownCard(label) => write!(f, "`{label}` isn't a card"),
+            RulesetError::RankedTwice(label) => write!(f, "card `{label}` is ranked twice"),
+        }
+    }
+}
+
+impl std::error::Error for RulesetError {}
+
+fn card(label: char) -> Result<Card, RulesetError> {
+    Card::from_label(label).ok_or(RulesetError::UnknownCard(label))
+}
+
+/// How two hands of the same kind are ordered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TieBreak {
+    /// Compare card by card in the order they were dealt (Camel Cards).
+    InOrder,
+    /// Compare the cards sorted strongest first.
+    HighestFirst,
+    /// Compare the biggest groups first, then the kickers (e.g. a pair of
+    /// kings beats a pair of queens whatever the other cards are).
+    Groups,
+}
+
+/// The rules a game of Camel Cards is played under.
+#[derive(Debug, Clone)]
+pub struct Ruleset {
+    ranking: Vec<Card>,
+    wild: Vec<Card>,
+    hand_size: usize,
+    tie_break: TieBreak,
+}
+
+impl Ruleset {
+    /// Constructs a ruleset with 5 card hands, no wild cards and in-order tie
+    /// breaking.
+    ///
+    /// * `ranking`: card labels from weakest to strongest (e.g.
+    ///   "A23456789TJQK" for aces low). Cards left out can't be dealt.
+    pub fn new(ranking: &str) -> Result<Self, RulesetError> {
+        let mut cards: Vec<Card> = vec![];
+        for label in ranking.chars() {
+            let card = card(label)?;
+            if cards.contains(&card) {
+                return Err(RulesetError::RankedTwice(label));
+            }
+            cards.push(card);
+        }
+
+        Ok(Ruleset {
+            ranking: cards,
+            wild: vec![],
+            hand_size: 5,
+            tie_break: TieBreak::InOrder,
+        })
+    }
+
+    /// The part one rules: J is a jack
+    pub fn camel_cards() -> Self {
+        Ruleset::new("23456789TJQKA").expect("every card is ranked once")
+    }
+
+    /// The part two rules: J is a joker, the weakest card but wild
+    pub fn jokers() -> Self {
+        Ruleset::new("J23456789TQKA")
+            .and_then(|rules| rules.with_wild("J"))
+            .expect("every card is ranked once")
+    }
+
+    /// Sets the cards that can stand in for any other card
+    ///
+    /// * `labels`: the labels of the wild cards
+    pub fn with_wild(mut self, labels: &str) -> Result<Self, RulesetError> {
+        self.wild = labels.chars().map(card).collect::<Result<_, _>>()?;
+        Ok(self)
+    }
+
+    /// Sets the number of cards in every hand
+    pub fn with_hand_size(mut self, hand_size: usize) -> Self {
+        self.hand_size = hand_size;
+        self
+    }
+
+    /// Sets how hands of the same kind are ordered
+    pub fn with_tie_break(mut self, tie_break: TieBreak) -> Self {
+        self.tie_break = tie_break;
+        self
+    }
+
+    pub fn wild(&self) -> &[Card] {
+        &self.wild
+    }
+
+    pub fn hand_size(&self) -> usize {
+        self.hand_size
+    }
+
+    pub fn tie_break(&self) -> TieBreak {
+        self.tie_break
+    }
+
+    /// Gets the strength of a card (0 is weakest), or None if the card isn't
+    /// in the ranking.
+    pub fn rank(&self, card: Card) -> Option<u8> {
+        self.ranking
+            .iter()
+            .position(|ranked| *ranked == card)
+            .map(|position| position as u8)
+    }
+
+    /// Gets the cards with their strengths in the order they should be compared
+    /// to break a tie between hands of the same kind.
+    ///
+    /// * `cards`: the cards in the hand
+    pub fn tie_break_ranks(&self, cards: &[Card]) -> Vec<(u8, Card)> {
+        let mut ranks: Vec<(u8, Card)> = cards
+            .iter()
+            .map(|card| (self.rank(*card).expect("card not in ranking."), *card))
+            .collect();
+
+        match self.tie_break {
+            TieBreak::InOrder => {}
+            TieBreak::HighestFirst => ranks.sort_unstable_by(|a, b| b.cmp(a)),
+            TieBreak::Groups => {
+                let count = |rank: &(u8, Card)| ranks.iter().filter(|other| *other == rank).count();
+                let mut grouped = ranks.clone();
+                grouped.sort_unstable_by(|a, b| count(b).cmp(&count(a)).then(b.cmp(a)));
+                ranks = grouped;
+            }
+        }
+        ranks
+    }
+}
+
+impl Default for Ruleset {
+    fn default() -> Self {
+        Ruleset::camel_cards()
+    }
+}
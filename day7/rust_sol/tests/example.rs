@@ -0,0 +1,16 @@
+use aoc::Solution;
+use day7::Day7;
+
+const EXAMPLE: &str = include_str!("../test.txt");
+
+#[test]
+fn part_one_example() {
+    let input = Day7::parse(EXAMPLE).unwrap();
+    assert_eq!(Day7::part_one(&input), 6440);
+}
+
+#[test]
+fn part_two_example() {
+    let input = Day7::parse(EXAMPLE).unwrap();
+    assert_eq!(Day7::part_two(&input), 5905);
+}
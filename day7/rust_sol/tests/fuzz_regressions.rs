@@ -0,0 +1,21 @@
+//! Inputs the fuzz targets in `fuzz/` found crashes on.
+
+use aoc::Solution;
+use day7::Day7;
+
+#[test]
+fn rejects_bad_hands() {
+    let inputs = [
+        "32T3K",
+        "32T3K 7 65",
+        "32T3K -5",
+        "32T3K 99999999999",
+        "KK6775 28",
+        "33 5",
+        "32T3\u{ff} 765",
+        "32t3K 765",
+    ];
+    for input in inputs {
+        assert!(Day7::parse(input).is_err(), "{input:?}");
+    }
+}
@@ -0,0 +1,89 @@
+use day7::{Card, HandKind};
+
+const CARDS: [Card; 13] = [
+    Card::Two,
+    Card::Three,
+    Card::Four,
+    Card::Five,
+    Card::Six,
+    Card::Seven,
+    Card::Eight,
+    Card::Nine,
+    Card::Ten,
+    Card::J,
+    Card::Q,
+    Card::K,
+    Card::A,
+];
+
+/// Classifies a hand without jokers by counting the copies of each card.
+fn classify_plain(cards: &[Card]) -> HandKind {
+    let mut counts = [0; 5];
+    for (count, card) in counts.iter_mut().zip(cards) {
+        *count = cards.iter().filter(|other| *other == card).count();
+    }
+    let max = *counts.iter().max().unwrap();
+    let pairs = counts.iter().filter(|count| **count == 2).count() / 2;
+
+    match (max, pairs) {
+        (5, _) => HandKind::FiveOfAKind,
+        (4, _) => HandKind::FourOfAKind,
+        (3, 1) => HandKind::FullHouse,
+        (3, _) => HandKind::ThreeOfAKind,
+        (2, 2) => HandKind::TwoPair,
+        (2, _) => HandKind::OnePair,
+        _ => HandKind::HighCard,
+    }
+}
+
+/// Tries every possible substitution for every joker (J) and keeps the best
+/// kind.
+fn classify_brute_force(cards: &mut [Card]) -> HandKind {
+    let Some(joker) = cards.iter().position(|card| *card == Card::J) else {
+        return classify_plain(cards);
+    };
+
+    let best = CARDS
+        .iter()
+        .filter(|substitute| **substitute != Card::J)
+        .map(|substitute| {
+            cards[joker] = *substitute;
+            classify_brute_force(cards)
+        })
+        .max()
+        .unwrap();
+    cards[joker] = Card::J;
+    best
+}
+
+#[test]
+fn every_hand_matches_brute_force() {
+    let mut hand = [Card::J; 5];
+    let total = CARDS.len().pow(5);
+
+    for index in 0..total {
+        let mut rest = index;
+        for card in hand.iter_mut() {
+            *card = CARDS[rest % CARDS.len()];
+            rest /= CARDS.len();
+        }
+
+        let expected = classify_brute_force(&mut hand.clone());
+        assert_eq!(
+            HandKind::from_cards(&hand, &[Card::J]),
+            expected,
+            "{hand:?}"
+        );
+    }
+}
+
+#[test]
+fn signatures() {
+    assert_eq!(HandKind::from_signature(&[5]), HandKind::FiveOfAKind);
+    assert_eq!(HandKind::from_signature(&[3, 2]), HandKind::FullHouse);
+    assert_eq!(HandKind::from_signature(&[2, 2, 1]), HandKind::TwoPair);
+    assert_eq!(
+        HandKind::from_signature(&[1, 1, 1, 1, 1]),
+        HandKind::HighCard
+    );
+}
@@ -0,0 +1,119 @@
+use day7::{
+    poker::{evaluate, parse_poker_hands, Suit},
+    Card, HandKind, Ranking,
+};
+
+/// Reads a hand like `AhKdQcJsTh`.
+fn cards(hand: &str) -> Vec<(Card, Suit)> {
+    let labels: Vec<char> = hand.chars().collect();
+    labels
+        .chunks(2)
+        .map(|pair| {
+            (
+                Card::from_label(pair[0]).unwrap(),
+                Suit::from_label(pair[1]).unwrap(),
+            )
+        })
+        .collect()
+}
+
+/// Gets the kind of a hand and the labels of its cards in tie breaking order.
+fn evaluated(hand: &str) -> (HandKind, String) {
+    let (kind, ranks) = evaluate(&cards(hand));
+    (kind, ranks.iter().map(|(_, card)| card.label()).collect())
+}
+
+#[test]
+fn evaluates_every_kind() {
+    let cases = [
+        ("2h7d9cJsKh", HandKind::HighCard, "KJ972"),
+        ("KhKd9c7s2h", HandKind::OnePair, "KK972"),
+        ("9h9dKcKs2h", HandKind::TwoPair, "KK992"),
+        ("5h5d5cKs2h", HandKind::ThreeOfAKind, "555K2"),
+        ("9h8dTc7sJh", HandKind::Straight, "J"),
+        ("9s7s5s3s2s", HandKind::Flush, "97532"),
+        ("2h2dKsKcKh", HandKind::FullHouse, "KKK22"),
+        ("4h4d4c4s9h", HandKind::FourOfAKind, "44449"),
+        ("9c8cTc7cJc", HandKind::StraightFlush, "J"),
+        ("AhKhQhJhTh", HandKind::StraightFlush, "A"),
+    ];
+    for (hand, kind, ranks) in cases {
+        assert_eq!(evaluated(hand), (kind, ranks.to_string()), "{hand}");
+    }
+}
+
+#[test]
+fn aces_play_low_in_a_straight() {
+    assert_eq!(
+        evaluated("Ah2c3d4s5h"),
+        (HandKind::Straight, "5".to_string())
+    );
+    assert_eq!(
+        evaluated("5d4d3dAd2d"),
+        (HandKind::StraightFlush, "5".to_string())
+    );
+    // but only as the bottom of A2345, there's no wrapping round
+    assert_eq!(evaluated("QhKdAc2s3h").0, HandKind::HighCard);
+
+    // the five-high straight is the weakest
+    assert!(evaluate(&cards("Ah2c3d4s5h")) < evaluate(&cards("2h3c4d5s6h")));
+}
+
+#[test]
+fn kickers_break_ties_after_the_groups() {
+    // (weaker, stronger)
+    let cases = [
+        ("KhKd9c7s2h", "KcKs9h7d3c"),
+        ("QhQdJcJsAh", "9h9dKcKs2h"),
+        ("9h9dKcKs2h", "9c9sKhKd3h"),
+        ("2h2d2cAsKh", "3h3d3c4s5h"),
+        ("9s7s5s3s2s", "Td7d5d3d2d"),
+        ("2h7d9cJsKh", "3h7c9dJhKs"),
+    ];
+    for (weaker, stronger) in cases {
+        assert_eq!(
+            evaluate(&cards(weaker)).0,
+            evaluate(&cards(stronger)).0,
+            "{weaker}"
+        );
+        assert!(
+            evaluate(&cards(weaker)) < evaluate(&cards(stronger)),
+            "{weaker} < {stronger}"
+        );
+    }
+}
+
+#[test]
+fn ranks_the_example() {
+    let hands = parse_poker_hands(include_str!("../poker.txt")).unwrap();
+    let ranking = Ranking::new(hands);
+    let ranked: Vec<String> = ranking.explain().into_iter().map(|row| row.cards).collect();
+    assert_eq!(ranked, ["A2345", "23456", "97532", "KKK22", "AKQJT"]);
+    assert_eq!(ranking.total_winnings(), Some(6731));
+}
+
+#[test]
+fn errors_point_at_the_card() {
+    // (input, column, message)
+    let cases = [
+        (
+            "AhKdQcJsTh",
+            11,
+            "expected a number, found the end of the line",
+        ),
+        ("AhKdXcJsTh 1", 5, "unexpected card `X`"),
+        ("AhKdQxJsTh 1", 6, "unexpected suit `x`"),
+        ("AhKdQcJsT 1", 10, "expected a suit, found ` `"),
+        ("AhKdQcJs 1", 1, "expected 5 cards in hand, found 4"),
+        ("AhKdQcJsTh9h 1", 1, "expected 5 cards in hand, found 6"),
+        ("AhKdQcKdTh 1", 7, "`Kd` dealt twice in hand"),
+    ];
+    for (input, column, message) in cases {
+        let err = parse_poker_hands(&format!("2h3d4c5s6h 7\n{input}\n")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!("line 2, column {column}: {message}"),
+            "{input}"
+        );
+    }
+}
@@ -0,0 +1,88 @@
+use aoc::{
+    generate::{self, Rng},
+    Solution,
+};
+use day7::Day7;
+
+/// Scores a hand without jokers from how many of each label it has: five of
+/// a kind is 6 down to high card at 0.
+fn kind(cards: &[char]) -> u8 {
+    let mut counts: Vec<usize> = cards
+        .iter()
+        .map(|card| cards.iter().filter(|other| *other == card).count())
+        .collect();
+    counts.sort_unstable();
+
+    match counts[..] {
+        [.., 5] => 6,
+        [.., 4] => 5,
+        [2, 2, 3, 3, 3] => 4,
+        [.., 3] => 3,
+        [_, 2, 2, 2, 2] => 2,
+        [.., 2] => 1,
+        _ => 0,
+    }
+}
+
+/// Scores a hand with jokers by trying every label for the jokers. Turning
+/// them all into the same label is always best (`hand_kind.rs` checks every
+/// substitution).
+fn joker_kind(cards: &[char]) -> u8 {
+    "23456789TQKA"
+        .chars()
+        .map(|label| {
+            let cards: Vec<char> = cards
+                .iter()
+                .map(|card| if *card == 'J' { label } else { *card })
+                .collect();
+            kind(&cards)
+        })
+        .max()
+        .unwrap()
+}
+
+/// Ranks the hands by kind, then card by card, and adds up rank times bid.
+fn winnings(input: &str, order: &str, jokers: bool) -> u64 {
+    let mut hands: Vec<(u8, Vec<usize>, u64)> = input
+        .lines()
+        .map(|line| {
+            let (cards, bid) = line.split_once(' ').unwrap();
+            let cards: Vec<char> = cards.chars().collect();
+            let kind = match jokers {
+                true => joker_kind(&cards),
+                false => kind(&cards),
+            };
+            let values = cards
+                .iter()
+                .map(|card| order.find(*card).unwrap())
+                .collect();
+            (kind, values, bid.parse().unwrap())
+        })
+        .collect();
+    hands.sort();
+
+    hands
+        .iter()
+        .enumerate()
+        .map(|(rank, (_, _, bid))| (rank as u64 + 1) * bid)
+        .sum()
+}
+
+#[test]
+fn matches_reference_on_random_inputs() {
+    for seed in 0..300 {
+        let input = generate::camel_hands(&mut Rng::new(seed), 50, 1000);
+        let rankings = Day7::parse(&input).unwrap();
+
+        assert_eq!(
+            Day7::part_one(&rankings),
+            winnings(&input, "23456789TJQKA", false).into(),
+            "seed {seed}:\n{input}"
+        );
+        assert_eq!(
+            Day7::part_two(&rankings),
+            winnings(&input, "J23456789TQKA", true).into(),
+            "seed {seed}:\n{input}"
+        );
+    }
+}
@@ -0,0 +1,92 @@
+use day7::{get_total_winings, parse_hands, Card, HandKind, Ranking, Ruleset};
+
+const EXAMPLE: &str = include_str!("../test.txt");
+
+fn example() -> Ranking {
+    Ranking::new(parse_hands(EXAMPLE, &Ruleset::camel_cards()).unwrap())
+}
+
+#[test]
+fn totals_the_example() {
+    let ranking = example();
+    assert_eq!(ranking.total_winnings(), Some(6440));
+    assert_eq!(ranking.total_winnings_wide(), 6440);
+    assert_eq!(get_total_winings(ranking.hands()), Some(6440));
+}
+
+#[test]
+fn explains_why_each_hand_beats_the_one_below() {
+    let expected = [
+        (1, "32T3K", HandKind::OnePair, None, 765),
+        (2, "KTJJT", HandKind::TwoPair, None, 440),
+        (3, "KK677", HandKind::TwoPair, Some(Card::K), 84),
+        (4, "T55J5", HandKind::ThreeOfAKind, None, 2736),
+        (5, "QQQJA", HandKind::ThreeOfAKind, Some(Card::Q), 2415),
+    ];
+    let rows = example().explain();
+    assert_eq!(rows.len(), expected.len());
+    for (row, (rank, cards, kind, tie_breaker, winnings)) in rows.iter().zip(expected) {
+        assert_eq!(row.rank, rank);
+        assert_eq!(row.cards, cards, "rank {rank}");
+        assert_eq!(row.kind, kind, "{cards}");
+        assert_eq!(row.tie_breaker, tie_breaker, "{cards}");
+        assert_eq!(row.winnings, Some(winnings), "{cards}");
+    }
+}
+
+#[test]
+fn a_tie_is_broken_by_the_first_different_card() {
+    let hands = parse_hands(
+        "2AAAA 1\n33332 2\nAAAA2 3\nAAAA3 4",
+        &Ruleset::camel_cards(),
+    )
+    .unwrap();
+    let breakers: Vec<_> = Ranking::new(hands)
+        .explain()
+        .into_iter()
+        .map(|row| (row.cards, row.tie_breaker))
+        .collect();
+    assert_eq!(
+        breakers,
+        [
+            ("2AAAA".to_string(), None),
+            ("33332".to_string(), Some(Card::Three)),
+            ("AAAA2".to_string(), Some(Card::A)),
+            ("AAAA3".to_string(), Some(Card::Three)),
+        ]
+    );
+}
+
+#[test]
+fn displays_a_table() {
+    let table = example().to_string();
+    let lines: Vec<&str> = table.lines().collect();
+    assert_eq!(lines.len(), 7);
+    assert_eq!(
+        lines[0],
+        "  rank  hand      kind             tie breaker         bid              winnings"
+    );
+    assert_eq!(
+        lines[3],
+        "     3  KK677     TwoPair          K                    28                    84"
+    );
+    assert_eq!(lines[6], "total winnings: 6440");
+}
+
+#[test]
+fn huge_bids_overflow_only_the_u64_total() {
+    // 100,000 hands bidding u32::MAX win about 2.1e19, more than a u64 holds
+    let hands = 100_000u128;
+    let input = "AAAAA 4294967295\n".repeat(hands as usize);
+    let ranking = Ranking::new(parse_hands(&input, &Ruleset::camel_cards()).unwrap());
+    let total = u32::MAX as u128 * hands * (hands + 1) / 2;
+    assert!(total > u64::MAX as u128);
+
+    assert_eq!(ranking.total_winnings(), None);
+    assert_eq!(get_total_winings(ranking.hands()), None);
+    assert_eq!(ranking.total_winnings_wide(), total);
+    assert!(ranking.explain().iter().all(|row| row.winnings.is_some()));
+    assert!(ranking
+        .to_string()
+        .ends_with(&format!("total winnings: {total}")));
+}
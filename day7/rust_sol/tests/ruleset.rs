@@ -0,0 +1,188 @@
+use day7::{parse_hands, Card, Hand, HandKind, Ranking, Ruleset, RulesetError, TieBreak};
+
+fn aces_low() -> Ruleset {
+    Ruleset::new("A23456789TJQK").unwrap()
+}
+
+/// Parses one hand (with a bid of 1) under `rules`.
+fn hand(cards: &str, rules: &Ruleset) -> Hand {
+    parse_hands(&format!("{cards} 1"), rules)
+        .unwrap()
+        .pop()
+        .unwrap()
+}
+
+fn labels(ranks: &[(u8, Card)]) -> String {
+    ranks.iter().map(|(_, card)| card.label()).collect()
+}
+
+#[test]
+fn ranks_follow_the_ranking() {
+    let cases = [
+        ("camel cards", Ruleset::camel_cards(), Card::A, Some(12)),
+        ("camel cards", Ruleset::camel_cards(), Card::J, Some(9)),
+        ("jokers", Ruleset::jokers(), Card::J, Some(0)),
+        ("aces low", aces_low(), Card::A, Some(0)),
+        ("aces low", aces_low(), Card::K, Some(12)),
+        (
+            "no faces",
+            Ruleset::new("23456789T").unwrap(),
+            Card::K,
+            None,
+        ),
+    ];
+    for (name, rules, card, rank) in cases {
+        assert_eq!(rules.rank(card), rank, "{name}: {card:?}");
+    }
+}
+
+#[test]
+fn the_default_rules_are_camel_cards() {
+    let rules = Ruleset::default();
+    assert_eq!(rules.hand_size(), 5);
+    assert_eq!(rules.tie_break(), TieBreak::InOrder);
+    assert!(rules.wild().is_empty());
+    assert_eq!(rules.rank(Card::A), Ruleset::camel_cards().rank(Card::A));
+}
+
+#[test]
+fn tie_breaks_order_the_cards() {
+    let cases = [
+        (TieBreak::InOrder, Ruleset::camel_cards(), "KTJJT", "KTJJT"),
+        (
+            TieBreak::HighestFirst,
+            Ruleset::camel_cards(),
+            "KTJJT",
+            "KJJTT",
+        ),
+        (TieBreak::Groups, Ruleset::camel_cards(), "KTJJT", "JJTTK"),
+        (TieBreak::HighestFirst, aces_low(), "A2K3Q", "KQ32A"),
+        (TieBreak::Groups, aces_low(), "A2A32", "22AA3"),
+    ];
+    for (tie_break, rules, cards, expected) in cases {
+        let rules = rules.with_tie_break(tie_break);
+        let cards: Vec<Card> = cards
+            .chars()
+            .map(|c| Card::from_label(c).unwrap())
+            .collect();
+        assert_eq!(
+            labels(&rules.tie_break_ranks(&cards)),
+            expected,
+            "{tie_break:?}"
+        );
+    }
+}
+
+#[test]
+fn hands_of_the_same_kind_are_ordered_by_the_tie_break() {
+    // (rules, weaker, stronger)
+    let cases = [
+        ("in order", Ruleset::camel_cards(), "2AAAA", "33332"),
+        (
+            "highest first",
+            Ruleset::camel_cards().with_tie_break(TieBreak::HighestFirst),
+            "33332",
+            "2AAAA",
+        ),
+        ("in order", Ruleset::camel_cards(), "33456", "AKQ22"),
+        (
+            "highest first",
+            Ruleset::camel_cards().with_tie_break(TieBreak::HighestFirst),
+            "33456",
+            "AKQ22",
+        ),
+        (
+            "groups",
+            Ruleset::camel_cards().with_tie_break(TieBreak::Groups),
+            "AKQ22",
+            "33456",
+        ),
+        ("aces low", aces_low(), "AAAKK", "222QQ"),
+        (
+            "aces low, groups",
+            aces_low().with_tie_break(TieBreak::Groups),
+            "KKAAA",
+            "QQ222",
+        ),
+        (
+            "T wild",
+            Ruleset::new("T23456789JQKA")
+                .and_then(|rules| rules.with_wild("T"))
+                .unwrap(),
+            "T2345",
+            "22345",
+        ),
+    ];
+    for (name, rules, weaker, stronger) in cases {
+        let (weak, strong) = (hand(weaker, &rules), hand(stronger, &rules));
+        assert_eq!(weak.kind(), strong.kind(), "{name}");
+        assert!(weak < strong, "{name}");
+
+        // the ranking puts them the same way round, and says which card did it
+        let ranked = Ranking::new(vec![strong, weak]).explain();
+        assert_eq!(ranked[1].cards, stronger, "{name}");
+        assert!(ranked[1].tie_breaker.is_some(), "{name}");
+    }
+}
+
+#[test]
+fn wild_cards_other_than_j() {
+    let rules = Ruleset::new("T23456789JQKA")
+        .and_then(|rules| rules.with_wild("T"))
+        .unwrap();
+    let cases = [
+        ("TTKKQ", HandKind::FourOfAKind),
+        ("TTTTT", HandKind::FiveOfAKind),
+        ("T2345", HandKind::OnePair),
+        // J is just a card here
+        ("JJ234", HandKind::OnePair),
+        ("JJJ2T", HandKind::FourOfAKind),
+    ];
+    for (cards, kind) in cases {
+        assert_eq!(hand(cards, &rules).kind(), kind, "{cards}");
+    }
+}
+
+#[test]
+fn hands_of_other_sizes() {
+    let three = Ruleset::camel_cards().with_hand_size(3);
+    let seven = Ruleset::camel_cards().with_hand_size(7);
+    let cases = [
+        (&three, "KKQ", HandKind::OnePair),
+        (&three, "KKK", HandKind::ThreeOfAKind),
+        (&three, "AKQ", HandKind::HighCard),
+        (&seven, "KKKKQQ2", HandKind::FourOfAKind),
+        (&seven, "AAAAAAK", HandKind::FiveOfAKind),
+        (&seven, "KKQQ234", HandKind::TwoPair),
+    ];
+    for (rules, cards, kind) in cases {
+        assert_eq!(hand(cards, rules).kind(), kind, "{cards}");
+    }
+
+    let err = parse_hands("KKQQ2 1", &three).unwrap_err();
+    assert!(
+        err.to_string()
+            .ends_with("expected 3 cards in hand, found 5"),
+        "{err}"
+    );
+    assert!(parse_hands("KKQ 1", &seven).is_err());
+}
+
+#[test]
+fn bad_rulesets_are_errors() {
+    let cases = [
+        ("23456789TJQKX", RulesetError::UnknownCard('X')),
+        ("23456789TJQKAK", RulesetError::RankedTwice('K')),
+    ];
+    for (ranking, expected) in cases {
+        assert_eq!(
+            Ruleset::new(ranking).map(|_| ()).unwrap_err(),
+            expected,
+            "{ranking}"
+        );
+    }
+
+    let err = Ruleset::camel_cards().with_wild("J?").unwrap_err();
+    assert_eq!(err, RulesetError::UnknownCard('?'));
+    assert_eq!(err.to_string(), "`?` isn't a card");
+}
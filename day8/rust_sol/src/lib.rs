@@ -1,4 +1,14 @@
-use std::collections::HashMap;
+use aoc::{
+    parse::{
+        self, delimited, key_value, line, lines, map, pair, section, spanned, tag, take_while1,
+        terminated, try_map, word, Parser,
+    },
+    trace::Trace,
+    ParseError, Solution,
+};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::collections::{HashMap, HashSet};
 
 #[derive(Debug)]
 enum Direction {
@@ -21,74 +31,193 @@ pub struct Map {
 fn gcd(a: usize, b: usize) -> usize {
     let remainder = a % b;
     if remainder == 0 {
-        return b;
+        b
     } else {
-        return gcd(b, remainder);
+        gcd(b, remainder)
     }
 }
 
-pub fn lcm(a: usize, b: usize) -> usize {
-    return a * b / gcd(a, b);
+/// Gets the least common multiple, or `None` if it doesn't fit in a `usize`.
+pub fn lcm(a: usize, b: usize) -> Option<usize> {
+    (a / gcd(a, b)).checked_mul(b)
 }
 
-pub fn get_node_count(start: &str, map: &Map) -> usize {
-    let mut count = 0;
-    let mut current = start.to_string();
+/// Counts the steps from `start` to `ZZZ`, or `None` if `start` isn't in the
+/// network or the walk never gets there.
+pub fn get_node_count(start: &str, map: &Map) -> Option<usize> {
+    walk(start, map, |node| node == "ZZZ", |_, _, _| {})
+}
 
-    while current != "ZZZ" {
-        let node = map.graph.get(&current).unwrap();
-        let next = match map.instructions[count % map.instructions.len()] {
-            Direction::Left => &node.left,
-            Direction::Right => &node.right,
-        };
-        current = next.to_string();
-        count += 1;
-    }
-    return count;
+/// Counts the steps from `start` to any node ending in `Z`, or `None` if
+/// `start` isn't in the network or the walk never gets to one.
+pub fn get_node_count_zend(start: &str, map: &Map) -> Option<usize> {
+    walk(start, map, |node| node.ends_with('Z'), |_, _, _| {})
 }
 
-pub fn get_node_count_zend(start: &str, map: &Map) -> usize {
+/// Follows the instructions from `start` until `is_end`, calling `step` with
+/// every node left, the instruction taken and the node it leads to.
+fn walk<'a>(
+    start: &str,
+    map: &'a Map,
+    is_end: impl Fn(&str) -> bool,
+    mut step: impl FnMut(&'a str, char, &'a str),
+) -> Option<usize> {
     let mut count = 0;
-    let mut current = start.to_string();
+    let mut current = map.graph.get_key_value(start)?.0;
+    // after this many steps some node has been reached at the same point in
+    // the instructions twice, so the walk is going round in circles
+    let limit = map.graph.len() * map.instructions.len();
 
-    while !current.ends_with("Z") {
-        let node = map.graph.get(&current).unwrap();
-        let next = match map.instructions[count % map.instructions.len()] {
-            Direction::Left => &node.left,
-            Direction::Right => &node.right,
+    while !is_end(current) {
+        if count > limit {
+            return None;
+        }
+        let node = &map.graph[current];
+        let (instruction, next) = match map.instructions[count % map.instructions.len()] {
+            Direction::Left => ('L', &node.left),
+            Direction::Right => ('R', &node.right),
         };
-        current = next.to_string();
+        step(current, instruction, next);
+        current = next;
         count += 1;
     }
-    return count;
+    Some(count)
 }
 
-pub fn parse_map(contents: &str) -> Map {
-    let lines: Vec<&str> = contents.lines().collect();
-    let directions = lines[0]
-        .chars()
-        .map(|char| {
-            return match char {
-                'L' => Direction::Left,
-                'R' => Direction::Right,
-                _ => Direction::Left,
-            };
-        })
-        .collect();
-    let mut graph = HashMap::new();
+/// Counts the steps until every ghost, starting from each node ending in
+/// `A`, is on a node ending in `Z`. Assumes each ghost then loops back to the
+/// same node as often as it took to get there, like in the real inputs.
+/// `None` if a ghost never gets to a node ending in `Z` or the count doesn't
+/// fit in a `usize`.
+pub fn get_ghost_count(map: &Map) -> Option<usize> {
+    let start = |key: &&String| key.ends_with('A');
+    let count = |key: &String| get_node_count_zend(key, map);
 
-    lines.iter().skip(2).for_each(|line| {
-        let parts: Vec<&str> = line.split_whitespace().collect();
+    #[cfg(feature = "parallel")]
+    if aoc::parallel::enabled() {
+        let keys = map.graph.par_iter().map(|(key, _)| key);
+        let counts: Option<Vec<usize>> = keys.filter(start).map(count).collect();
+        return counts?.into_iter().try_fold(1, lcm);
+    }
+    let counts: Option<Vec<usize>> = map.graph.keys().filter(start).map(count).collect();
+    counts?.into_iter().try_fold(1, lcm)
+}
 
-        let key = parts[0];
-        let left = parts[2].replace("(", "").replace(",", "");
-        let right = parts[3].replace(")", "");
-        let node = MapNode { left, right };
-        graph.insert(key.to_string(), node);
+/// Reads the instructions and the network. Every node a node leads to has
+/// to be in the network.
+pub fn parse_map(contents: &str) -> Result<Map, ParseError> {
+    let instructions = take_while1("instructions", |c| !c.is_whitespace());
+    let directions = try_map(instructions, |line| {
+        line.chars()
+            .map(|char| match char {
+                'L' => Ok(Direction::Left),
+                'R' => Ok(Direction::Right),
+                _ => Err(format!("unexpected instruction `{char}`")),
+            })
+            .collect()
     });
+    let (instructions, nodes) = parse::run(
+        pair(section(line(directions)), lines(spanned(node()))),
+        contents,
+    )?;
+
+    let keys: HashSet<&str> = nodes.iter().map(|node| node.value.0).collect();
+    for node in &nodes {
+        let (_, MapNode { left, right }) = &node.value;
+        if let Some(missing) = [left, right]
+            .into_iter()
+            .find(|next| !keys.contains(next.as_str()))
+        {
+            return Err(format!(
+                "line {}: node {missing} is missing from the network",
+                node.span.line
+            )
+            .into());
+        }
+    }
+    let graph = nodes
+        .into_iter()
+        .map(|node| (node.value.0.to_string(), node.value.1))
+        .collect();
 
-    Map {
-        instructions: directions,
+    Ok(Map {
+        instructions,
         graph,
+    })
+}
+
+/// Parses a node and where it leads, `AAA = (BBB, CCC)`.
+fn node<'a>() -> impl Parser<'a, (&'a str, MapNode)> {
+    let next = delimited(
+        tag("("),
+        pair(terminated(word(), tag(", ")), word()),
+        tag(")"),
+    );
+
+    map(key_value(word(), "=", next), |(key, (left, right))| {
+        (
+            key,
+            MapNode {
+                left: left.to_string(),
+                right: right.to_string(),
+            },
+        )
+    })
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    type Input = Map;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_map(input)
+    }
+
+    fn part_one(map: &Self::Input) -> usize {
+        get_node_count("AAA", map).expect("ZZZ can't be reached from AAA")
+    }
+
+    fn part_two(map: &Self::Input) -> usize {
+        get_ghost_count(map).expect("the ghosts never all reach a node ending in Z")
+    }
+
+    fn explain(map: &Self::Input, trace: &mut Trace) {
+        trace.heading("part one, from AAA to ZZZ");
+        let mut steps = 0;
+        let count = walk(
+            "AAA",
+            map,
+            |node| node == "ZZZ",
+            |from, instruction, to| {
+                steps += 1;
+                trace.line(format_args!("step {steps}: {from} -{instruction}-> {to}"));
+            },
+        );
+        match (map.graph.contains_key("AAA"), count) {
+            (false, _) => trace.line("there's no AAA in the network"),
+            (true, None) => trace.line("the walk goes round in circles without reaching ZZZ"),
+            (true, Some(count)) => trace.line(format_args!("ZZZ after {count} steps")),
+        }
+
+        trace.heading("part two, every ghost from its A node to a Z node");
+        let mut starts: Vec<&String> = map.graph.keys().filter(|key| key.ends_with('A')).collect();
+        starts.sort();
+        for start in starts {
+            let mut end = start.as_str();
+            match walk(start, map, |node| node.ends_with('Z'), |_, _, to| end = to) {
+                Some(count) => trace.line(format_args!("{start}: {end} after {count} steps")),
+                None => trace.line(format_args!("{start}: never reaches a node ending in Z")),
+            }
+        }
+        if let Some(count) = get_ghost_count(map) {
+            trace.line(format_args!(
+                "all on a Z node together after {count} steps (the lcm)"
+            ));
+        }
     }
 }
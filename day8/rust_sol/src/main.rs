@@ -1,17 +1,15 @@
-use std::fs;
-use rust_sol::{get_node_count, get_node_count_zend, lcm, parse_map};
+use aoc::{source, trace, Solution};
+use day8::Day8;
+use std::process;
 
 fn main() {
-    let contents = fs::read_to_string("input.txt").expect("Couldn't open file.");
-    let map = parse_map(&contents);
-    let count = get_node_count("AAA", &map);
-    println!("Answer 1: {}", count);
+    let (contents, flags) = source::read_or_exit(Day8::DAY, env!("CARGO_MANIFEST_DIR"), &[]);
+    let map = Day8::parse(&contents).unwrap_or_else(|err| {
+        eprintln!("couldn't parse input: {err}");
+        process::exit(1);
+    });
 
-    let total_steps: usize = map
-        .graph
-        .keys()
-        .filter(|key| key.ends_with("A"))
-        .map(|key| get_node_count_zend(key, &map))
-        .fold(0, |acc, x| lcm(acc, x));
-    println!("Answer 2: {}", total_steps);
+    println!("Answer 1: {}", Day8::part_one(&map));
+    println!("Answer 2: {}", Day8::part_two(&map));
+    trace::print_if_asked::<Day8>(&map, &flags);
 }
@@ -0,0 +1,18 @@
+use aoc::Solution;
+use day8::Day8;
+
+const EXAMPLE: &str = include_str!("../test.txt");
+// part two has its own example
+const EXAMPLE_TWO: &str = include_str!("../test2.txt");
+
+#[test]
+fn part_one_example() {
+    let input = Day8::parse(EXAMPLE).unwrap();
+    assert_eq!(Day8::part_one(&input), 2);
+}
+
+#[test]
+fn part_two_example() {
+    let input = Day8::parse(EXAMPLE_TWO).unwrap();
+    assert_eq!(Day8::part_two(&input), 6);
+}
@@ -0,0 +1,33 @@
+//! Inputs the fuzz targets in `fuzz/` found crashes on.
+
+use aoc::Solution;
+use day8::Day8;
+
+#[test]
+fn rejects_bad_networks() {
+    let inputs = [
+        "",
+        "\u{ef}",
+        "LX\n\nAAA = (AAA, AAA)",
+        "LR\nAAA = (AAA, AAA)",
+        "LR\n\n11A = (, XX)",
+        "LR\n\ns = (20 )\n1B1",
+        "LR\n\n= (11B, (11B = (= (22B, XXX)",
+        "LR\n\nAAA = (BBB, CCC)",
+    ];
+    for input in inputs {
+        assert!(Day8::parse(input).is_err(), "{input:?}");
+    }
+}
+
+#[test]
+fn walks_that_never_end() {
+    let map = Day8::parse("L\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)").unwrap();
+    assert_eq!(day8::get_node_count("AAA", &map), None);
+    assert_eq!(day8::get_ghost_count(&map), None);
+
+    // the second example has no AAA
+    let map = Day8::parse(include_str!("../test2.txt")).unwrap();
+    assert_eq!(day8::get_node_count("AAA", &map), None);
+    assert_eq!(day8::get_ghost_count(&map), Some(6));
+}
@@ -0,0 +1,69 @@
+use std::collections::HashMap;
+
+use aoc::{
+    generate::{self, Rng},
+    Solution,
+};
+use day8::Day8;
+
+/// Reads the instructions and the left and right node of every node.
+fn read(input: &str) -> (Vec<char>, HashMap<&str, (&str, &str)>) {
+    let (instructions, nodes) = input.split_once("\n\n").unwrap();
+    let nodes = nodes
+        .lines()
+        .map(|line| {
+            let (name, next) = line.split_once(" = ").unwrap();
+            let (left, right) = next.trim_matches(['(', ')']).split_once(", ").unwrap();
+            (name, (left, right))
+        })
+        .collect();
+    (instructions.chars().collect(), nodes)
+}
+
+/// Walks every ghost at once until they're all on a node that `is_end`.
+fn walk_together(
+    input: &str,
+    starts: impl Fn(&str) -> bool,
+    is_end: impl Fn(&str) -> bool,
+) -> usize {
+    let (instructions, nodes) = read(input);
+    let mut ghosts: Vec<&str> = nodes.keys().copied().filter(|name| starts(name)).collect();
+
+    let mut steps = 0;
+    while !ghosts.iter().all(|ghost| is_end(ghost)) {
+        for ghost in ghosts.iter_mut() {
+            let (left, right) = nodes[ghost];
+            *ghost = match instructions[steps % instructions.len()] {
+                'L' => left,
+                _ => right,
+            };
+        }
+        steps += 1;
+    }
+    steps
+}
+
+#[test]
+fn matches_reference_on_random_inputs() {
+    for seed in 0..300 {
+        let mut rng = Rng::new(seed);
+        let (ghosts, instructions) = (rng.range(1..=4) as usize, rng.range(1..=5) as usize);
+        let input = generate::network(&mut rng, ghosts, instructions, 6);
+        let map = Day8::parse(&input).unwrap();
+
+        assert_eq!(
+            Day8::part_one(&map),
+            walk_together(&input, |name| name == "AAA", |name| name == "ZZZ"),
+            "seed {seed}:\n{input}"
+        );
+        assert_eq!(
+            Day8::part_two(&map),
+            walk_together(
+                &input,
+                |name| name.ends_with('A'),
+                |name| name.ends_with('Z')
+            ),
+            "seed {seed}:\n{input}"
+        );
+    }
+}
@@ -0,0 +1,9 @@
+use aoc::Solution;
+use day8::Day8;
+
+// part two folded the lcm of the walks from 0, and lcm(0, n) is 0
+#[test]
+fn part_two_folds_from_one() {
+    let map = Day8::parse(include_str!("../test2.txt")).unwrap();
+    assert_eq!(Day8::part_two(&map), 6);
+}
@@ -0,0 +1,154 @@
+use aoc::{
+    parse::{self, integer, lines, list, try_map},
+    ParseError, Solution,
+};
+
+/// Mirage Maintenance: extrapolates the OASIS report's histories one value
+/// forwards and one backwards.
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<Vec<i64>>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(parse_histories(input)?)
+    }
+
+    fn part_one(histories: &Self::Input) -> i64 {
+        sum_extrapolated(histories, next_value).expect("the next values don't fit in an i64")
+    }
+
+    fn part_two(histories: &Self::Input) -> i64 {
+        sum_extrapolated(histories, previous_value)
+            .expect("the previous values don't fit in an i64")
+    }
+}
+
+/// Reads the histories, one line of numbers each. A history has at least one
+/// value.
+pub fn parse_histories(contents: &str) -> Result<Vec<Vec<i64>>, parse::Error> {
+    let history = try_map(list(integer::<i64>()), |values| match values.is_empty() {
+        true => Err("a history needs at least one value".to_string()),
+        false => Ok(values),
+    });
+    parse::run(lines(history), contents)
+}
+
+/// Adds up a value extrapolated from every history, or `None` if a value or
+/// the sum doesn't fit in an `i64`.
+///
+/// * `histories`: the histories
+/// * `extrapolate`: `next_value`, `previous_value` or another way to get them
+pub fn sum_extrapolated(
+    histories: &[Vec<i64>],
+    extrapolate: impl Fn(&[i64]) -> Option<i128>,
+) -> Option<i64> {
+    histories.iter().try_fold(0i64, |sum, history| {
+        sum.checked_add(i64::try_from(extrapolate(history)?).ok()?)
+    })
+}
+
+/// Takes differences of the history until they're all zero (or there's one
+/// left), giving the history and every row of differences under it. `None` if
+/// a difference doesn't fit in an `i128`.
+pub fn differences(history: &[i64]) -> Option<Vec<Vec<i128>>> {
+    let mut rows: Vec<Vec<i128>> = vec![history.iter().map(|&value| value as i128).collect()];
+
+    loop {
+        let last = &rows[rows.len() - 1];
+        if last.len() <= 1 || last.iter().all(|&value| value == 0) {
+            return Some(rows);
+        }
+        let row = last
+            .windows(2)
+            .map(|pair| pair[1].checked_sub(pair[0]))
+            .collect::<Option<Vec<i128>>>()?;
+        rows.push(row);
+    }
+}
+
+/// Extrapolates the value after the history: each row of differences grows by
+/// its last value plus the new value of the row under it. `None` if a number
+/// doesn't fit in an `i128`.
+pub fn next_value(history: &[i64]) -> Option<i128> {
+    differences(history)?
+        .iter()
+        .rev()
+        .try_fold(0i128, |below, row| row.last()?.checked_add(below))
+}
+
+/// Extrapolates the value before the history, like `next_value` but with each
+/// row's first value. `None` if a number doesn't fit in an `i128`.
+pub fn previous_value(history: &[i64]) -> Option<i128> {
+    differences(history)?
+        .iter()
+        .rev()
+        .try_fold(0i128, |below, row| row.first()?.checked_sub(below))
+}
+
+/// Evaluates the polynomial through the history (the value at `i` being
+/// `history[i]`) at `x` with the Lagrange form, without taking differences.
+/// Gives the same numbers as `next_value` at `x = history.len()` and
+/// `previous_value` at `x = -1`. `None` if a number doesn't fit in an `i128`.
+///
+/// * `history`: the polynomial's values at 0, 1, 2...
+/// * `x`: where to evaluate it
+pub fn lagrange(history: &[i64], x: i64) -> Option<i128> {
+    let last = history.len() as i128 - 1;
+    let x = x as i128;
+
+    history
+        .iter()
+        .enumerate()
+        .try_fold(0i128, |sum, (i, &value)| {
+            let i = i as i128;
+            // the basis polynomial for `i` is the product of (x - j) / (i - j)
+            // over the other points, which splits into two binomials
+            let basis = binomial(x, i)?.checked_mul(binomial(x - i - 1, last - i)?)?;
+            let basis = match (last - i) % 2 {
+                0 => basis,
+                _ => basis.checked_neg()?,
+            };
+            sum.checked_add(basis.checked_mul(value as i128)?)
+        })
+}
+
+/// Gets `n` choose `k` for any integer `n`, i.e. `n (n - 1) ... (n - k + 1)`
+/// divided by `k!`, which is always a whole number.
+fn binomial(n: i128, k: i128) -> Option<i128> {
+    (0..k).try_fold(1i128, |product, step| {
+        // product is `n` choose `step`, and times `n - step` it divides by
+        // `step + 1` exactly
+        Some(product.checked_mul(n - step)? / (step + 1))
+    })
+}
+
+/// Checks that taking differences and the Lagrange form extrapolate every
+/// history to the same values, both ways. A value one of them can't work out
+/// in an `i128` (the Lagrange form's binomials outgrow it on long histories)
+/// can't be checked, so it's skipped rather than counted as a disagreement.
+pub fn compare_methods(histories: &[Vec<i64>]) -> Result<(), String> {
+    for (number, history) in histories.iter().enumerate() {
+        let len = history.len() as i64;
+        for (which, differences, lagrange) in [
+            ("next", next_value(history), lagrange(history, len)),
+            ("previous", previous_value(history), lagrange(history, -1)),
+        ] {
+            let (Some(differences), Some(lagrange)) = (differences, lagrange) else {
+                continue;
+            };
+            if differences != lagrange {
+                return Err(format!(
+                    "history {}: the {which} value is {differences} from the differences \
+                     but {lagrange} from the Lagrange form",
+                    number + 1
+                ));
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use aoc::{source, trace, Solution};
+use day9::Day9;
+use std::process;
+
+fn main() {
+    let (contents, flags) =
+        source::read_or_exit(Day9::DAY, env!("CARGO_MANIFEST_DIR"), &["--check"]);
+    let check = flags.iter().any(|flag| flag == "--check");
+    let histories = Day9::parse(&contents).unwrap_or_else(|err| {
+        eprintln!("couldn't parse input: {err}");
+        process::exit(1);
+    });
+
+    if check {
+        match day9::compare_methods(&histories) {
+            Ok(()) => println!("differences and Lagrange agree on every value both can work out"),
+            Err(err) => {
+                eprintln!("{err}");
+                process::exit(1);
+            }
+        }
+    }
+
+    println!("Answer 1: {}", Day9::part_one(&histories));
+    println!("Answer 2: {}", Day9::part_two(&histories));
+    trace::print_if_asked::<Day9>(&histories, &flags);
+}
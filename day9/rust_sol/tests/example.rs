@@ -0,0 +1,26 @@
+use aoc::Solution;
+use day9::Day9;
+
+const EXAMPLE: &str = include_str!("../test.txt");
+
+#[test]
+fn part_one_example() {
+    let input = Day9::parse(EXAMPLE).unwrap();
+    assert_eq!(Day9::part_one(&input), 114);
+}
+
+#[test]
+fn part_two_example() {
+    let input = Day9::parse(EXAMPLE).unwrap();
+    assert_eq!(Day9::part_two(&input), 2);
+}
+
+#[test]
+fn lagrange_agrees_with_differences() {
+    let input = Day9::parse(EXAMPLE).unwrap();
+    assert_eq!(day9::compare_methods(&input), Ok(()));
+    assert_eq!(day9::lagrange(&input[2], 6), Some(68));
+    assert_eq!(day9::lagrange(&input[2], -1), Some(5));
+    // on the history itself it's the history
+    assert_eq!(day9::lagrange(&input[2], 3), Some(21));
+}
@@ -0,0 +1,71 @@
+use aoc::{
+    generate::{self, Rng},
+    Solution,
+};
+use day9::Day9;
+
+/// Extrapolates a history both ways by recursing on its differences.
+fn extrapolate(history: &[i128]) -> (i128, i128) {
+    if history.iter().all(|&value| value == 0) || history.len() == 1 {
+        return (history[history.len() - 1], history[0]);
+    }
+    let differences: Vec<i128> = history.windows(2).map(|pair| pair[1] - pair[0]).collect();
+    let (next, previous) = extrapolate(&differences);
+    (history[history.len() - 1] + next, history[0] - previous)
+}
+
+#[test]
+fn matches_reference_on_random_inputs() {
+    for seed in 0..300 {
+        let input = generate::histories(&mut Rng::new(seed), 5, 12, 8);
+        let histories = Day9::parse(&input).unwrap();
+
+        let (next, previous) = histories.iter().fold((0, 0), |(next, previous), history| {
+            let history: Vec<i128> = history.iter().map(|&value| value as i128).collect();
+            let (one, two) = extrapolate(&history);
+            (next + one, previous + two)
+        });
+
+        assert_eq!(
+            Day9::part_one(&histories) as i128,
+            next,
+            "seed {seed}:\n{input}"
+        );
+        assert_eq!(
+            Day9::part_two(&histories) as i128,
+            previous,
+            "seed {seed}:\n{input}"
+        );
+        assert_eq!(
+            day9::compare_methods(&histories),
+            Ok(()),
+            "seed {seed}:\n{input}"
+        );
+    }
+}
+
+#[test]
+fn overflow_is_caught() {
+    let history = [i64::MAX, i64::MIN, i64::MAX, i64::MIN];
+    let histories = vec![history.to_vec()];
+
+    // fine in an i128, too big for the i64 answer
+    assert!(day9::next_value(&history).is_some());
+    assert_eq!(day9::sum_extrapolated(&histories, day9::next_value), None);
+    assert_eq!(day9::compare_methods(&histories), Ok(()));
+}
+
+#[test]
+fn long_histories_are_only_checked_where_lagrange_fits() {
+    // the Lagrange form's binomials for 150 points don't fit in an i128
+    let zeros = vec![0; 150];
+    assert_eq!(day9::next_value(&zeros), Some(0));
+    assert_eq!(day9::lagrange(&zeros, 150), None);
+
+    let squares = (0..150).map(|i| i * i).collect::<Vec<i64>>();
+    assert_eq!(day9::next_value(&squares), Some(150 * 150));
+    assert_eq!(day9::previous_value(&squares), Some(1));
+
+    let histories = vec![zeros, squares];
+    assert_eq!(day9::compare_methods(&histories), Ok(()));
+}
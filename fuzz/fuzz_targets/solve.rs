@@ -0,0 +1,71 @@
+#![no_main]
+
+use aoc::Solution;
+use libfuzzer_sys::fuzz_target;
+
+/// Solves both parts if the input parses.
+fn solve<S: Solution>(input: &str) {
+    if let Ok(parsed) = S::parse(input) {
+        S::part_one(&parsed);
+        S::part_two(&parsed);
+    }
+}
+
+// the first byte picks the day, the rest is its input
+fuzz_target!(|data: &[u8]| {
+    let Some((day, input)) = data.split_first() else {
+        return;
+    };
+    let Ok(input) = std::str::from_utf8(input) else {
+        return;
+    };
+
+    match day % 12 + 1 {
+        1 => solve::<day1::Day1>(input),
+        2 => solve::<day2::Day2>(input),
+        3 => solve::<day3::Day3>(input),
+        4 => solve::<day4::Day4>(input),
+        // part two searches every location until one maps back to a seed,
+        // which takes forever on made-up almanacs
+        5 => {
+            if let Ok(almanac) = day5::Day5::parse(input) {
+                day5::Day5::part_one(&almanac);
+            }
+        }
+        6 => solve::<day6::Day6>(input),
+        7 => solve::<day7::Day7>(input),
+        // a made-up network doesn't have to have an answer, so the parts
+        // would panic saying so
+        8 => {
+            if let Ok(map) = day8::Day8::parse(input) {
+                day8::get_node_count("AAA", &map);
+                day8::get_ghost_count(&map);
+            }
+        }
+        // the parts panic if an answer doesn't fit in an i64, and both ways
+        // of extrapolating have to agree
+        9 => {
+            if let Ok(histories) = day9::Day9::parse(input) {
+                day9::sum_extrapolated(&histories, day9::next_value);
+                day9::sum_extrapolated(&histories, day9::previous_value);
+                day9::compare_methods(&histories).unwrap();
+            }
+        }
+        // both ways of counting the enclosed tiles have to agree
+        10 => {
+            if let Ok(maze) = day10::Day10::parse(input) {
+                day10::Day10::part_one(&maze);
+                day10::compare_methods(&maze).unwrap();
+            }
+        }
+        11 => solve::<day11::Day11>(input),
+        // made-up rows can have more arrangements than fit in a u64, which the
+        // parts panic on
+        _ => {
+            if let Ok(rows) = day12::Day12::parse(input) {
+                day12::total_arrangements(&rows, 1);
+                day12::total_arrangements(&rows, 5);
+            }
+        }
+    }
+});
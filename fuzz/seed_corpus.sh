@@ -0,0 +1,14 @@
+#!/bin/sh
+# Seeds every fuzz target's corpus with the example inputs. The solve target
+# takes the day (minus one) as its first byte.
+cd "$(dirname "$0")" || exit 1
+
+for dir in ../day*/rust_sol; do
+    day=$(basename "$(dirname "$dir")" | tr -d 'day')
+    mkdir -p "corpus/parse_day$day" corpus/solve
+    for example in "$dir"/test*.txt; do
+        name=$(basename "$example")
+        cp "$example" "corpus/parse_day$day/$name"
+        { printf "\\$(printf '%03o' $((day - 1)))"; cat "$example"; } > "corpus/solve/day$day-$name"
+    done
+done
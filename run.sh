@@ -1,13 +1,9 @@
 #!/bin/sh
+# usage: ./run.sh <dayN|all> [--format table|csv|json]
 
-path=$1"/rust_sol"
-
-if [ ! -d $path ]; then
-    echo "$path does not exist."
-    echo "Please enter file with rust solution."
+if [ -z "$1" ]; then
+    echo "Please enter a day (e.g. day5) or all."
 else
-    cd $path
-    cargo run --release
-    cd ../..
+    cd "$(dirname "$0")"
+    cargo run --release --quiet -p runner -- run "$@"
 fi
-    
@@ -0,0 +1,43 @@
+use aoc::{trace::Trace, ParseError, Solution};
+
+use crate::report::{self, Report};
+
+/// A solved day, with its `Solution` erased so every day can be run from the
+/// same list.
+pub struct Day {
+    pub day: u8,
+    pub run: fn(&str) -> Result<Report, ParseError>,
+    /// Explains the answers, keeping up to the given number of lines
+    pub explain: fn(&str, usize) -> Result<Trace, ParseError>,
+}
+
+const fn register<S: Solution>() -> Day {
+    Day {
+        day: S::DAY,
+        run: report::run::<S>,
+        explain: report::explain::<S>,
+    }
+}
+
+/// Every day the runner knows about, in order.
+pub const DAYS: &[Day] = &[
+    register::<day1::Day1>(),
+    register::<day2::Day2>(),
+    register::<day3::Day3>(),
+    register::<day4::Day4>(),
+    register::<day5::Day5>(),
+    register::<day6::Day6>(),
+    register::<day7::Day7>(),
+    register::<day8::Day8>(),
+    register::<day9::Day9>(),
+    register::<day10::Day10>(),
+    register::<day11::Day11>(),
+    register::<day12::Day12>(),
+];
+
+/// Finds a registered day
+///
+/// * `day`: the day number
+pub fn find(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|entry| entry.day == day)
+}
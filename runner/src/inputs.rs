@@ -0,0 +1,54 @@
+use std::path::PathBuf;
+
+use aoc::{
+    answers::Answers,
+    input::{self, InputManager, UreqBackend},
+    source::{self, InputSource, SourceError},
+};
+
+/// The year every solved day belongs to.
+pub use aoc::YEAR;
+
+/// Gets the root of the workspace the runner was built in.
+pub fn workspace_root() -> PathBuf {
+    PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/.."))
+}
+
+/// Gets a day's crate directory, where its hand-copied `input.txt` sits.
+pub fn day_dir(day: u8) -> PathBuf {
+    workspace_root().join(format!("day{day}/rust_sol"))
+}
+
+/// Builds the input manager, using the cache picked by `input::cache_dir`.
+pub fn manager() -> InputManager<UreqBackend> {
+    let manager = InputManager::new(input::cache_dir(&workspace_root()), UreqBackend::new());
+    match input::find_session() {
+        Some(session) => manager.with_session(&session),
+        None => manager,
+    }
+}
+
+/// Checks whether a day's input can be read without fetching it.
+pub fn have(day: u8) -> bool {
+    source::read_default(day, &day_dir(day)).is_ok()
+}
+
+/// Reads a day's input from `source`. The default is the hand-copied
+/// `input.txt` if there is one (and it isn't the empty placeholder of a new
+/// day), otherwise the cached input, fetching it the first time.
+pub fn read(day: u8, source: &InputSource) -> Result<String, String> {
+    match source.read(day, &day_dir(day)) {
+        Ok(input) => Ok(input),
+        Err(SourceError::Missing { .. }) => manager()
+            .get(YEAR, day)
+            .map_err(|err| format!("couldn't get input: {err}")),
+        Err(err) => Err(err.to_string()),
+    }
+}
+
+/// Loads the confirmed answers, kept in the input cache with the inputs they
+/// belong to.
+pub fn answers() -> Result<Answers, String> {
+    let path = manager().cache_dir().join("answers.txt");
+    Answers::load(&path).map_err(|err| format!("couldn't read {}: {err}", path.display()))
+}
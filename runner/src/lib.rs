@@ -0,0 +1,9 @@
+//! The pieces of the `aoc` command, kept out of `main.rs` so they can be
+//! tested.
+
+pub mod days;
+pub mod inputs;
+pub mod output;
+pub mod report;
+pub mod scaffold;
+pub mod watch;
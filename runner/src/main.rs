@@ -0,0 +1,386 @@
+use std::{env, panic, process, thread};
+
+use aoc::{
+    answers::{self, Check},
+    source::InputSource,
+    submit::{self, History, Refusal, SubmitError, Verdict},
+    trace, ParseError,
+};
+
+use runner::{
+    days::{self, Day, DAYS},
+    inputs,
+    output::{self, Format},
+    report::{Failure, Report},
+    scaffold, watch,
+};
+
+const USAGE: &str = "usage:
+    aoc run <day|all> [--format table|csv|json] [--input <path|->] [--input-str <input>]
+            [--parallel] [--explain [--explain-limit <lines>]]
+    aoc fetch <day|all>
+    aoc submit <day> <1|2> [answer]
+    aoc confirm <day|all>
+    aoc verify [day|all]
+    aoc new <day>
+    aoc watch <day>";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let result = match args.first().map(String::as_str) {
+        Some("run") => run(&args[1..]),
+        Some("fetch") => fetch(&args[1..]),
+        Some("submit") => submit(&args[1..]),
+        Some("confirm") => confirm(&args[1..]),
+        Some("verify") => verify(&args[1..]),
+        Some("new") => new(&args[1..]),
+        Some("watch") => watch(&args[1..]),
+        _ => Err(USAGE.to_string()),
+    };
+
+    if let Err(err) = result {
+        eprintln!("{err}");
+        process::exit(1);
+    }
+}
+
+/// `aoc run <day|all>`: solves the selected days and prints the answers with
+/// how long each step took. A single day can be given its input as a file,
+/// on stdin (`--input -`) or inline. With `--parallel` every day runs on its
+/// own thread at once, so the times include waiting for a core. `--explain`
+/// adds each day's trace of how it found the answers.
+fn run(args: &[String]) -> Result<(), String> {
+    let mut selection = None;
+    let mut format = Format::Table;
+    let mut source = InputSource::Default;
+    let mut parallel = false;
+    let mut explain = false;
+    let mut explain_limit = trace::DEFAULT_LIMIT;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => format = args.next().ok_or(USAGE)?.parse()?,
+            "--input" => {
+                source = match args.next().ok_or(USAGE)?.as_str() {
+                    "-" => InputSource::Stdin,
+                    path => InputSource::Path(path.into()),
+                }
+            }
+            "--input-str" => source = InputSource::Inline(args.next().ok_or(USAGE)?.clone()),
+            "--parallel" => parallel = true,
+            "--explain" => explain = true,
+            "--explain-limit" => {
+                let limit = args.next().ok_or(USAGE)?;
+                explain_limit = limit
+                    .parse()
+                    .map_err(|_| format!("`{limit}` isn't a number of lines"))?;
+            }
+            _ if selection.is_none() => selection = Some(arg.as_str()),
+            _ => return Err(USAGE.to_string()),
+        }
+    }
+
+    let days = select_days(selection.ok_or(USAGE)?)?;
+    if days.len() > 1 && source != InputSource::Default {
+        return Err("an input can only be given when running a single day".to_string());
+    }
+
+    // read one at a time even in parallel, so fetching missing inputs keeps to
+    // the rate limit
+    let inputs: Vec<(&Day, Result<String, String>)> = days
+        .into_iter()
+        .map(|day| (day, inputs::read(day.day, &source)))
+        .collect();
+    let solve = |day: &Day, input: &Result<String, String>| {
+        input.clone().and_then(|input| solve_day(day, &input))
+    };
+    let results: Vec<(&Day, Result<Report, String>)> = if parallel {
+        thread::scope(|scope| {
+            let handles: Vec<_> = inputs
+                .iter()
+                .map(|(day, input)| (*day, scope.spawn(move || solve(day, input))))
+                .collect();
+            handles
+                .into_iter()
+                .map(|(day, handle)| (day, handle.join().expect("solve_day catches panics")))
+                .collect()
+        })
+    } else {
+        inputs
+            .iter()
+            .map(|(day, input)| (*day, solve(day, input)))
+            .collect()
+    };
+
+    let mut reports = vec![];
+    let mut failures = vec![];
+    for (day, result) in results {
+        match result {
+            Ok(report) => reports.push(report),
+            Err(error) => {
+                eprintln!("day {}: {error}", day.day);
+                failures.push(Failure {
+                    day: day.day,
+                    error,
+                });
+            }
+        }
+    }
+
+    output::print(&reports, &failures, format);
+    if explain {
+        for (day, input) in &inputs {
+            if let Ok(input) = input {
+                explain_day(day, input, explain_limit, format);
+            }
+        }
+    }
+    match failures.len() {
+        0 => Ok(()),
+        failed => Err(format!("{failed} day(s) failed")),
+    }
+}
+
+/// Reads a day's input and runs it.
+fn run_day(day: &Day, source: &InputSource) -> Result<Report, String> {
+    solve_day(day, &inputs::read(day.day, source)?)
+}
+
+/// Runs a day on its input. A panicking solver is reported as an error so the
+/// other days still run.
+fn solve_day(day: &Day, input: &str) -> Result<Report, String> {
+    catch_panic(|| (day.run)(input))
+}
+
+/// Prints a day's explanation, after the answers. It goes to the error output
+/// with `--format csv` or `json`, to keep their output readable by programs.
+fn explain_day(day: &Day, input: &str, limit: usize, format: Format) {
+    let explanation = match catch_panic(|| (day.explain)(input, limit)) {
+        Ok(trace) if trace.lines().is_empty() => "nothing to explain\n".to_string(),
+        Ok(trace) => trace.to_string(),
+        Err(err) => format!("{err}\n"),
+    };
+    let explanation = format!("\nDay {} explained\n{explanation}", day.day);
+    match format {
+        Format::Table => print!("{explanation}"),
+        Format::Csv | Format::Json => eprint!("{explanation}"),
+    }
+}
+
+/// Runs one of a day's functions, turning a parse error or a panic into an
+/// error message.
+fn catch_panic<T>(f: impl FnOnce() -> Result<T, ParseError>) -> Result<T, String> {
+    match panic::catch_unwind(panic::AssertUnwindSafe(f)) {
+        Ok(result) => result.map_err(|err| format!("couldn't parse input: {err}")),
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|message| message.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_default();
+            Err(format!("panicked: {message}"))
+        }
+    }
+}
+
+/// `aoc fetch <day|all>`: downloads the inputs that aren't cached yet.
+fn fetch(args: &[String]) -> Result<(), String> {
+    let [selection] = args else {
+        return Err(USAGE.to_string());
+    };
+    let manager = inputs::manager();
+
+    for day in select_days(selection)? {
+        let path = manager.path(inputs::YEAR, day.day);
+        if inputs::have(day.day) {
+            println!("day {}: already have input", day.day);
+            continue;
+        }
+        manager
+            .get(inputs::YEAR, day.day)
+            .map_err(|err| format!("day {}: {err}", day.day))?;
+        println!("day {}: saved to {}", day.day, path.display());
+    }
+    Ok(())
+}
+
+/// `aoc submit <day> <1|2> [answer]`: submits an answer, solving the day
+/// first if it isn't given. Answers the history already rules out aren't sent,
+/// and accepted ones are added to the confirmed answers.
+fn submit(args: &[String]) -> Result<(), String> {
+    let (day, part, answer) = match args {
+        [day, part] => (day, part, None),
+        [day, part, answer] => (day, part, Some(answer.clone())),
+        _ => return Err(USAGE.to_string()),
+    };
+    let day = parse_day(day)?;
+    let part = match part.as_str() {
+        "1" => 1,
+        "2" => 2,
+        _ => return Err(format!("`{part}` isn't a part (expected 1 or 2)")),
+    };
+
+    let input = inputs::read(day, &InputSource::Default)?;
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let entry = days::find(day).ok_or(format!("day {day} isn't solved yet"))?;
+            let report =
+                (entry.run)(&input).map_err(|err| format!("couldn't parse input: {err}"))?;
+            match part {
+                1 => report.answer_one,
+                _ => report.answer_two,
+            }
+        }
+    };
+
+    println!("day {day} part {part}: submitting {answer}");
+    let verdict = match submit::submit(&inputs::manager(), inputs::YEAR, day, part, &answer) {
+        Ok(verdict) => verdict,
+        Err(SubmitError::Refused(Refusal::AlreadySolved(correct))) => {
+            // it may have been solved before answers were confirmed here
+            confirm_answer(day, part, &input, &correct)?;
+            return Err(format!(
+                "day {day} part {part}: already solved, the answer was {correct}"
+            ));
+        }
+        Err(err) => return Err(format!("day {day} part {part}: {err}")),
+    };
+    println!("day {day} part {part}: {verdict}");
+    match verdict {
+        Verdict::Correct => confirm_answer(day, part, &input, answer.trim()),
+        _ => Err(format!("day {day} part {part}: answer not accepted")),
+    }
+}
+
+fn confirm_answer(day: u8, part: u8, input: &str, answer: &str) -> Result<(), String> {
+    let mut answers = inputs::answers()?;
+    answers
+        .confirm(day, part, answers::input_hash(input), answer)
+        .map_err(|err| {
+            format!(
+                "couldn't save answer to {}: {err}",
+                answers.path().display()
+            )
+        })
+}
+
+/// `aoc confirm <day|all>`: records the answers the submission history says
+/// were accepted as confirmed, for days submitted before answers were tracked.
+/// Parts without an accepted answer are only listed.
+fn confirm(args: &[String]) -> Result<(), String> {
+    let [selection] = args else {
+        return Err(USAGE.to_string());
+    };
+    let manager = inputs::manager();
+    let mut answers = inputs::answers()?;
+
+    for day in select_days(selection)? {
+        let day = day.day;
+        let input =
+            inputs::read(day, &InputSource::Default).map_err(|err| format!("day {day}: {err}"))?;
+        let path = submit::history_path(&manager, inputs::YEAR, day);
+        let history = History::load(&path)
+            .map_err(|err| format!("couldn't read {}: {err}", path.display()))?;
+
+        let confirmed = answers
+            .confirm_accepted(day, answers::input_hash(&input), &history)
+            .map_err(|err| {
+                format!(
+                    "couldn't save answer to {}: {err}",
+                    answers.path().display()
+                )
+            })?;
+        for part in [1, 2] {
+            match confirmed.iter().find(|(confirmed, _)| *confirmed == part) {
+                Some((_, answer)) => println!("day {day} part {part}: confirmed {answer}"),
+                None => println!("day {day} part {part}: no accepted answer to confirm"),
+            }
+        }
+    }
+    Ok(())
+}
+
+/// `aoc verify [day|all]`: re-runs the days and fails if an answer differs
+/// from the confirmed one. Answers that were never confirmed are only listed.
+fn verify(args: &[String]) -> Result<(), String> {
+    let selection = match args {
+        [] => "all",
+        [selection] => selection,
+        _ => return Err(USAGE.to_string()),
+    };
+    let answers = inputs::answers()?;
+
+    let mut failed = 0;
+    for day in select_days(selection)? {
+        let report = match run_day(day, &InputSource::Default) {
+            Ok(report) => report,
+            Err(err) => {
+                println!("day {}: {err}", day.day);
+                failed += 1;
+                continue;
+            }
+        };
+        for (part, answer) in [(1, &report.answer_one), (2, &report.answer_two)] {
+            let check = answers.check(report.day, part, report.input_hash, answer);
+            match check {
+                Check::Differs(_) => {
+                    println!("day {} part {part}: got {answer}, {check}", report.day);
+                    failed += 1;
+                }
+                _ => println!("day {} part {part}: {check}", report.day),
+            }
+        }
+    }
+
+    match failed {
+        0 => Ok(()),
+        _ => Err(format!("{failed} answer(s) didn't match")),
+    }
+}
+
+/// `aoc new <day>`: creates a new day from the template and registers it.
+fn new(args: &[String]) -> Result<(), String> {
+    let [day] = args else {
+        return Err(USAGE.to_string());
+    };
+    let day = parse_day(day)?;
+    if days::find(day).is_some() {
+        return Err(format!("day {day} is already registered"));
+    }
+
+    scaffold::new_day(&inputs::workspace_root(), day)?;
+    println!("created day{day}/rust_sol, run it with `./run.sh day{day}`");
+    Ok(())
+}
+
+/// `aoc watch <day>`: re-runs a day and its example tests whenever its code or
+/// input changes.
+fn watch(args: &[String]) -> Result<(), String> {
+    let [day] = args else {
+        return Err(USAGE.to_string());
+    };
+    watch::watch(parse_day(day)?)
+}
+
+/// Gets the days picked on the command line: `all` or a single day.
+fn select_days(selection: &str) -> Result<Vec<&'static Day>, String> {
+    match selection {
+        "all" => Ok(DAYS.iter().collect()),
+        day => {
+            let number = parse_day(day)?;
+            let day = days::find(number).ok_or(format!("day {number} isn't solved yet"))?;
+            Ok(vec![day])
+        }
+    }
+}
+
+/// Parses a day given as `5` or `day5`.
+fn parse_day(day: &str) -> Result<u8, String> {
+    day.strip_prefix("day")
+        .unwrap_or(day)
+        .parse()
+        .map_err(|_| format!("`{day}` isn't a day (expected e.g. 5 or day5)"))
+}
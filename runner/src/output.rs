@@ -0,0 +1,195 @@
+use std::{str::FromStr, time::Duration};
+
+use crate::report::{Failure, Report};
+
+/// How the runner prints its results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// The answers followed by a timing table
+    Table,
+    Csv,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(format: &str) -> Result<Self, Self::Err> {
+        match format {
+            "table" => Ok(Format::Table),
+            "csv" => Ok(Format::Csv),
+            "json" => Ok(Format::Json),
+            _ => Err(format!(
+                "unknown format `{format}` (expected table, csv or json)"
+            )),
+        }
+    }
+}
+
+/// Prints the reports in the given format, see `render`.
+pub fn print(reports: &[Report], failures: &[Failure], format: Format) {
+    print!("{}", render(reports, failures, format));
+}
+
+/// Writes the reports in the given format. Only JSON includes the failures,
+/// the other formats leave them to the error output.
+pub fn render(reports: &[Report], failures: &[Failure], format: Format) -> String {
+    match format {
+        Format::Table if reports.is_empty() => String::new(),
+        Format::Table => table(reports),
+        Format::Csv => csv(reports),
+        Format::Json => json(reports, failures),
+    }
+}
+
+fn totals(reports: &[Report]) -> [Duration; 4] {
+    reports.iter().fold([Duration::ZERO; 4], |totals, report| {
+        [
+            totals[0] + report.parse,
+            totals[1] + report.part_one,
+            totals[2] + report.part_two,
+            totals[3] + report.total(),
+        ]
+    })
+}
+
+fn table(reports: &[Report]) -> String {
+    let mut output = String::new();
+    for report in reports {
+        output += &format!(
+            "Day {}\nAnswer 1: {}\nAnswer 2: {}\n\n",
+            report.day, report.answer_one, report.answer_two
+        );
+    }
+
+    output += &format!(
+        "{:>5}  {:>10}  {:>10}  {:>10}  {:>10}\n",
+        "day", "parse", "part one", "part two", "total"
+    );
+    for report in reports {
+        output += &format!(
+            "{:>5}  {:>10}  {:>10}  {:>10}  {:>10}\n",
+            report.day,
+            format_duration(report.parse),
+            format_duration(report.part_one),
+            format_duration(report.part_two),
+            format_duration(report.total()),
+        );
+    }
+    if reports.len() > 1 {
+        let [parse, part_one, part_two, total] = totals(reports);
+        output += &format!(
+            "{:>5}  {:>10}  {:>10}  {:>10}  {:>10}\n",
+            "total",
+            format_duration(parse),
+            format_duration(part_one),
+            format_duration(part_two),
+            format_duration(total),
+        );
+    }
+    output
+}
+
+/// Formats a duration with a unit that keeps it readable (e.g. 12.3ms).
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos() as f64;
+    match nanos {
+        n if n < 1e3 => format!("{n}ns"),
+        n if n < 1e6 => format!("{:.1}µs", n / 1e3),
+        n if n < 1e9 => format!("{:.1}ms", n / 1e6),
+        n => format!("{:.2}s", n / 1e9),
+    }
+}
+
+fn csv(reports: &[Report]) -> String {
+    let mut output =
+        String::from("day,parse_ns,part_one_ns,part_two_ns,total_ns,answer_one,answer_two\n");
+    for report in reports {
+        output += &format!(
+            "{},{},{},{},{},{},{}\n",
+            report.day,
+            report.parse.as_nanos(),
+            report.part_one.as_nanos(),
+            report.part_two.as_nanos(),
+            report.total().as_nanos(),
+            csv_field(&report.answer_one),
+            csv_field(&report.answer_two),
+        );
+    }
+    let [parse, part_one, part_two, total] = totals(reports);
+    output += &format!(
+        "total,{},{},{},{},,\n",
+        parse.as_nanos(),
+        part_one.as_nanos(),
+        part_two.as_nanos(),
+        total.as_nanos()
+    );
+    output
+}
+
+fn csv_field(field: &str) -> String {
+    match field.contains([',', '"', '\n']) {
+        true => format!("\"{}\"", field.replace('"', "\"\"")),
+        false => field.to_string(),
+    }
+}
+
+fn json(reports: &[Report], failures: &[Failure]) -> String {
+    let mut days: Vec<(u8, String)> = reports
+        .iter()
+        .map(|report| {
+            let object = format!(
+                "{{\"day\":{},\"answer_one\":{},\"answer_two\":{},\"answer_one_type\":{},\"answer_two_type\":{},\"parse_ns\":{},\"part_one_ns\":{},\"part_two_ns\":{},\"total_ns\":{},\"error\":null}}",
+                report.day,
+                json_string(&report.answer_one),
+                json_string(&report.answer_two),
+                json_string(report.answer_one_type),
+                json_string(report.answer_two_type),
+                report.parse.as_nanos(),
+                report.part_one.as_nanos(),
+                report.part_two.as_nanos(),
+                report.total().as_nanos(),
+            );
+            (report.day, object)
+        })
+        .collect();
+    // failed days keep their place, with nulls where the results would be
+    days.extend(failures.iter().map(|failure| {
+        let object = format!(
+            "{{\"day\":{},\"answer_one\":null,\"answer_two\":null,\"answer_one_type\":null,\"answer_two_type\":null,\"parse_ns\":null,\"part_one_ns\":null,\"part_two_ns\":null,\"total_ns\":null,\"error\":{}}}",
+            failure.day,
+            json_string(&failure.error),
+        );
+        (failure.day, object)
+    }));
+    days.sort_by_key(|(day, _)| *day);
+    let days: Vec<String> = days.into_iter().map(|(_, object)| object).collect();
+    let [parse, part_one, part_two, total] = totals(reports);
+
+    format!(
+        "{{\"days\":[{}],\"total\":{{\"parse_ns\":{},\"part_one_ns\":{},\"part_two_ns\":{},\"total_ns\":{}}}}}\n",
+        days.join(","),
+        parse.as_nanos(),
+        part_one.as_nanos(),
+        part_two.as_nanos(),
+        total.as_nanos()
+    )
+}
+
+/// Quotes and escapes a string for JSON output.
+pub fn json_string(value: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '"' => quoted += "\\\"",
+            '\\' => quoted += "\\\\",
+            '\n' => quoted += "\\n",
+            '\r' => quoted += "\\r",
+            '\t' => quoted += "\\t",
+            c if c.is_control() => quoted += &format!("\\u{:04x}", c as u32),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
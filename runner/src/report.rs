@@ -0,0 +1,85 @@
+use std::{
+    any,
+    time::{Duration, Instant},
+};
+
+use aoc::{answers, trace::Trace, ParseError, Solution};
+
+/// The answers for one day and how long each step took.
+#[derive(Debug)]
+pub struct Report {
+    pub day: u8,
+    /// The hash of the input the answers are for
+    pub input_hash: u64,
+    pub answer_one: String,
+    pub answer_two: String,
+    /// The types of the answers, e.g. `u64`
+    pub answer_one_type: &'static str,
+    pub answer_two_type: &'static str,
+    pub parse: Duration,
+    pub part_one: Duration,
+    pub part_two: Duration,
+}
+
+impl Report {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part_one + self.part_two
+    }
+}
+
+/// A day that couldn't be run, and why.
+#[derive(Debug)]
+pub struct Failure {
+    pub day: u8,
+    pub error: String,
+}
+
+/// Parses the input and solves both parts, timing each step.
+///
+/// * `input`: the contents of the input file
+pub fn run<S: Solution>(input: &str) -> Result<Report, ParseError> {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    let answer_one = S::part_one(&parsed).to_string();
+    let part_one = start.elapsed();
+
+    let start = Instant::now();
+    let answer_two = S::part_two(&parsed).to_string();
+    let part_two = start.elapsed();
+
+    Ok(Report {
+        day: S::DAY,
+        input_hash: answers::input_hash(input),
+        answer_one,
+        answer_two,
+        answer_one_type: type_name::<S::Answer1>(),
+        answer_two_type: type_name::<S::Answer2>(),
+        parse,
+        part_one,
+        part_two,
+    })
+}
+
+/// Parses the input and explains how the answers are found.
+///
+/// * `input`: the contents of the input file
+/// * `limit`: how many lines of explanation to keep
+pub fn explain<S: Solution>(input: &str, limit: usize) -> Result<Trace, ParseError> {
+    let parsed = S::parse(input)?;
+    let mut trace = Trace::new(limit);
+    S::explain(&parsed, &mut trace);
+    Ok(trace)
+}
+
+/// Gets a type's name without its module path, e.g. `String` rather than
+/// `alloc::string::String`.
+fn type_name<T>() -> &'static str {
+    let name = any::type_name::<T>();
+    match name.contains('<') {
+        true => name,
+        false => name.rsplit("::").next().unwrap_or(name),
+    }
+}
@@ -0,0 +1,133 @@
+//! `aoc new <day>`: creates a day's crate from the templates in `templates/`
+//! and registers it with the workspace and the runner.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// The files of a new day, relative to its crate directory.
+const FILES: &[(&str, &str)] = &[
+    ("Cargo.toml", include_str!("../templates/Cargo.toml.txt")),
+    ("src/lib.rs", include_str!("../templates/lib.rs.txt")),
+    ("src/main.rs", include_str!("../templates/main.rs.txt")),
+    (
+        "benches/solution.rs",
+        include_str!("../templates/bench.rs.txt"),
+    ),
+    (
+        "tests/example.rs",
+        include_str!("../templates/example.rs.txt"),
+    ),
+    // filled in by hand, or `input.txt` is left empty and the input is fetched
+    ("test.txt", ""),
+    ("input.txt", ""),
+];
+
+/// Creates `day<N>/rust_sol` and adds it to the workspace, the runner's
+/// dependencies and its list of days. Nothing is written if the day exists.
+///
+/// * `root`: the workspace root, see `inputs::workspace_root`
+/// * `day`: the day number
+pub fn new_day(root: &Path, day: u8) -> Result<(), String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("there's no day {day}, days go from 1 to 25"));
+    }
+
+    let dir = root.join(format!("day{day}/rust_sol"));
+    if dir.exists() {
+        return Err(format!("day {day} already exists at {}", dir.display()));
+    }
+
+    // every line that registers a day, as the file, the start of the lines
+    // listing days and the new line
+    let lines = [
+        (
+            "Cargo.toml",
+            "    \"day",
+            format!("    \"day{day}/rust_sol\","),
+        ),
+        (
+            "Cargo.toml",
+            "day",
+            format!("day{day} = {{ path = \"day{day}/rust_sol\" }}"),
+        ),
+        (
+            "runner/Cargo.toml",
+            "day",
+            format!("day{day}.workspace = true"),
+        ),
+        (
+            "runner/src/days.rs",
+            "    register::<day",
+            format!("    register::<day{day}::Day{day}>(),"),
+        ),
+    ];
+    // work out every edit first, so a file that can't be updated doesn't
+    // leave the day half registered
+    let mut registrations: Vec<(PathBuf, String)> = vec![];
+    for (file, prefix, line) in lines {
+        let path = root.join(file);
+        let contents = match registrations.iter().position(|(edited, _)| *edited == path) {
+            Some(index) => registrations.remove(index).1,
+            None => read(&path)?,
+        };
+        let contents = register(&contents, day, prefix, &line)
+            .map_err(|err| format!("couldn't register day {day} in {}: {err}", path.display()))?;
+        registrations.push((path, contents));
+    }
+
+    for (file, template) in FILES {
+        let path = dir.join(file);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|err| format!("couldn't create {}: {err}", parent.display()))?;
+        }
+        write(&path, &template.replace("{{day}}", &day.to_string()))?;
+    }
+    for (path, contents) in registrations {
+        write(&path, &contents)?;
+    }
+    Ok(())
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|err| format!("couldn't read {}: {err}", path.display()))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    fs::write(path, contents).map_err(|err| format!("couldn't write {}: {err}", path.display()))
+}
+
+/// Reads the day number that follows `prefix` at the start of a line.
+fn number_after(line: &str, prefix: &str) -> Option<u8> {
+    let rest = line.strip_prefix(prefix)?;
+    let end = rest.find(|c: char| !c.is_ascii_digit())?;
+    rest[..end].parse().ok()
+}
+
+/// Adds a line to a list of per-day lines, keeping the days in order.
+///
+/// * `contents`: the file to add the line to
+/// * `day`: the day the line is for
+/// * `prefix`: what the lines in the list start with, up to the day number
+/// * `line`: the line to add
+fn register(contents: &str, day: u8, prefix: &str, line: &str) -> Result<String, String> {
+    let mut lines: Vec<&str> = contents.lines().collect();
+    let listed: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| Some((index, number_after(line, prefix)?)))
+        .collect();
+
+    if listed.iter().any(|&(_, listed)| listed == day) {
+        return Err(format!("day {day} is already listed"));
+    }
+    let index = match listed.iter().rev().find(|&&(_, listed)| listed < day) {
+        Some(&(index, _)) => index + 1,
+        None => listed.first().ok_or("couldn't find the list of days")?.0,
+    };
+
+    lines.insert(index, line);
+    Ok(lines.join("\n") + "\n")
+}
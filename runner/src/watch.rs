@@ -0,0 +1,188 @@
+//! `aoc watch <day>`: re-runs a day every time its code or input changes.
+//!
+//! The day is rebuilt and run by a fresh `cargo run`, since the runner that's
+//! watching has the old code compiled in.
+
+use std::{
+    collections::BTreeMap,
+    env,
+    ffi::OsString,
+    fs,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use aoc::{answers, source::InputSource};
+
+use crate::inputs;
+
+/// How often the watched files are checked.
+const POLL: Duration = Duration::from_millis(500);
+
+/// When each watched file was last modified.
+type Snapshot = BTreeMap<PathBuf, SystemTime>;
+
+/// Runs a day, then again after every change to its sources, tests, inputs or
+/// the shared `aoc` crate, until interrupted.
+///
+/// * `day`: the day number
+pub fn watch(day: u8) -> Result<(), String> {
+    let dir = inputs::day_dir(day);
+    if !dir.is_dir() {
+        return Err(format!("day {day} doesn't exist yet, try `aoc new {day}`"));
+    }
+
+    let mut last = None;
+    let mut files = snapshot(day);
+    loop {
+        // a failed run keeps the answers from before it to compare against
+        if let Some(answers) = run(day, last.as_ref()) {
+            last = Some(answers);
+        }
+        println!("\nwatching day{day}/rust_sol for changes, ^C to stop");
+        files = wait_for_change(day, files);
+    }
+}
+
+/// Gets the files a day's answers depend on.
+fn snapshot(day: u8) -> Snapshot {
+    let root = inputs::workspace_root();
+    let dir = inputs::day_dir(day);
+    let mut files = Snapshot::new();
+
+    for path in [dir.join("src"), dir.join("tests"), dir.join("Cargo.toml")] {
+        add(&path, &mut files);
+    }
+    // the example and hand-copied inputs
+    if let Ok(entries) = fs::read_dir(&dir) {
+        for entry in entries.flatten() {
+            if entry.path().extension().is_some_and(|ext| ext == "txt") {
+                add(&entry.path(), &mut files);
+            }
+        }
+    }
+    add(&root.join("aoc/src"), &mut files);
+    add(&inputs::manager().path(inputs::YEAR, day), &mut files);
+    files
+}
+
+/// Adds a file, or every file under a directory. Missing paths are skipped,
+/// so a file appearing later counts as a change.
+fn add(path: &Path, files: &mut Snapshot) {
+    if path.is_dir() {
+        for entry in fs::read_dir(path).into_iter().flatten().flatten() {
+            add(&entry.path(), files);
+        }
+    } else if let Ok(modified) = fs::metadata(path).and_then(|meta| meta.modified()) {
+        files.insert(path.to_path_buf(), modified);
+    }
+}
+
+/// Waits until a watched file is added, changed or removed, and prints which.
+fn wait_for_change(day: u8, before: Snapshot) -> Snapshot {
+    loop {
+        thread::sleep(POLL);
+        let after = snapshot(day);
+        if after == before {
+            continue;
+        }
+
+        // editors often write a file more than once, wait for them to finish
+        thread::sleep(POLL);
+        let after = snapshot(day);
+        let root = inputs::workspace_root();
+        let changed = after
+            .iter()
+            .filter(|(path, modified)| before.get(*path) != Some(modified))
+            .map(|(path, _)| path)
+            .chain(before.keys().filter(|path| !after.contains_key(*path)));
+        for path in changed {
+            let path = path.strip_prefix(&root).unwrap_or(path);
+            println!("changed: {}", path.display());
+        }
+        return after;
+    }
+}
+
+/// Rebuilds and runs the day and its example tests, then compares its answers
+/// with the last run's and the confirmed ones.
+///
+/// * `last`: the answers from the last run that had any
+fn run(day: u8, last: Option<&[String; 2]>) -> Option<[String; 2]> {
+    println!("\n== day {day}");
+    let day_arg = day.to_string();
+    let output = cargo(&[
+        "run",
+        "--release",
+        "-q",
+        "-p",
+        "runner",
+        "--",
+        "run",
+        &day_arg,
+    ])
+    .stdout(Stdio::piped())
+    .output();
+    let output = match output {
+        Ok(output) => output,
+        Err(err) => {
+            println!("couldn't run cargo: {err}");
+            return None;
+        }
+    };
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    print!("{stdout}");
+
+    let package = format!("day{day}");
+    if inputs::day_dir(day).join("tests/example.rs").exists() {
+        let tests = cargo(&["test", "-q", "-p", &package, "--test", "example"]).status();
+        match tests {
+            Ok(status) if status.success() => println!("example tests passed"),
+            Ok(_) => println!("example tests FAILED"),
+            Err(err) => println!("couldn't run the example tests: {err}"),
+        }
+    } else {
+        println!("no example tests in day{day}/rust_sol/tests/example.rs");
+    }
+
+    let answer = |prefix: &str| {
+        stdout
+            .lines()
+            .find_map(|line| line.strip_prefix(prefix))
+            .map(str::to_string)
+    };
+    let (Some(one), Some(two)) = (answer("Answer 1: "), answer("Answer 2: ")) else {
+        println!("no answers, see the errors above");
+        return None;
+    };
+    let answers = [one, two];
+
+    let confirmed = inputs::read(day, &InputSource::Default)
+        .ok()
+        .zip(inputs::answers().ok());
+    for (part, answer) in (1..=2).zip(&answers) {
+        let mut line = format!("part {part}: {answer}");
+        match last.map(|last| &last[part as usize - 1]) {
+            Some(before) if before == answer => line += " (unchanged",
+            Some(before) => line += &format!(" (was {before}"),
+            None => line += " (first run",
+        }
+        if let Some((input, confirmed)) = &confirmed {
+            let check = confirmed.check(day, part, answers::input_hash(input), answer);
+            line += &format!(", {check}");
+        }
+        println!("{line})");
+    }
+    Some(answers)
+}
+
+/// Builds a cargo command run from the workspace root, with the cargo that
+/// built the runner if there is one.
+fn cargo(args: &[&str]) -> Command {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| OsString::from("cargo"));
+    let mut command = Command::new(cargo);
+    command.args(args).current_dir(inputs::workspace_root());
+    command
+}
@@ -0,0 +1,93 @@
+use std::{
+    io::Write,
+    process::{Command, Output, Stdio},
+};
+
+const RACES: &str = "Time: 7 15 30\nDistance: 9 40 200\n";
+
+/// Runs `aoc` with the arguments, writing `stdin` to it.
+fn aoc(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+/// Gets the answers from the CSV output's row for a day.
+fn answers(output: &Output) -> Vec<String> {
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let row = stdout.lines().nth(1).unwrap_or_default();
+    row.split(',').skip(5).map(str::to_string).collect()
+}
+
+#[test]
+fn reads_the_input_from_stdin_a_string_or_a_file() {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/../day6/rust_sol/test.txt");
+    let cases = [
+        (vec!["--input", "-"], RACES),
+        (vec!["--input-str", RACES], ""),
+        (vec!["--input", path], ""),
+    ];
+    for (flags, stdin) in cases {
+        let mut args = vec!["run", "6", "--format", "csv"];
+        args.extend(&flags);
+        let output = aoc(&args, stdin);
+        assert!(output.status.success(), "{flags:?}: {output:?}");
+        assert_eq!(answers(&output), ["288", "71503"], "{flags:?}");
+    }
+}
+
+#[test]
+fn an_input_is_for_a_single_day() {
+    let output = aoc(&["run", "all", "--input-str", RACES], "");
+    assert!(!output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "an input can only be given when running a single day\n"
+    );
+}
+
+#[test]
+fn a_missing_input_file_is_an_error() {
+    let output = aoc(&["run", "6", "--input", "no/such/input.txt"], "");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.starts_with("day 6: couldn't read input file no/such/input.txt"),
+        "{stderr}"
+    );
+}
+
+#[test]
+fn a_failed_day_gets_an_error_entry_in_json() {
+    let output = aoc(
+        &["run", "6", "--input-str", "garbage", "--format", "json"],
+        "",
+    );
+    assert!(!output.status.success());
+
+    let error = "couldn't parse input: line 1, column 1: expected `Time:`, found `garbage`";
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.starts_with("{\"days\":[{\"day\":6,\"answer_one\":null,"),
+        "{stdout}"
+    );
+    assert!(
+        stdout.contains(&format!("\"error\":\"{error}\"}}]")),
+        "{stdout}"
+    );
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        format!("day 6: {error}\n1 day(s) failed\n")
+    );
+}
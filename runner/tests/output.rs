@@ -0,0 +1,94 @@
+use std::time::Duration;
+
+use runner::{
+    output::{self, json_string, Format},
+    report::{Failure, Report},
+};
+
+/// A report for `day` with the given answers, taking 1, 2 and 3 microseconds.
+fn report(day: u8, answer_one: &str, answer_two: &str) -> Report {
+    Report {
+        day,
+        input_hash: 0,
+        answer_one: answer_one.to_string(),
+        answer_two: answer_two.to_string(),
+        answer_one_type: "u64",
+        answer_two_type: "String",
+        parse: Duration::from_micros(1),
+        part_one: Duration::from_micros(2),
+        part_two: Duration::from_micros(3),
+    }
+}
+
+#[test]
+fn json_strings_are_escaped() {
+    let cases = [
+        ("plain", "\"plain\""),
+        ("say \"hi\"", "\"say \\\"hi\\\"\""),
+        ("back\\slash", "\"back\\\\slash\""),
+        ("two\nlines\r\n", "\"two\\nlines\\r\\n\""),
+        ("tab\there", "\"tab\\there\""),
+        ("bell\u{7}", "\"bell\\u0007\""),
+        ("µs", "\"µs\""),
+    ];
+    for (value, expected) in cases {
+        assert_eq!(json_string(value), expected, "{value:?}");
+    }
+}
+
+#[test]
+fn csv_fields_are_quoted_when_needed() {
+    let reports = [report(3, "1,2", "say \"hi\"\nbye")];
+    let csv = output::render(&reports, &[], Format::Csv);
+    assert_eq!(
+        csv,
+        "day,parse_ns,part_one_ns,part_two_ns,total_ns,answer_one,answer_two\n\
+         3,1000,2000,3000,6000,\"1,2\",\"say \"\"hi\"\"\nbye\"\n\
+         total,1000,2000,3000,6000,,\n"
+    );
+}
+
+#[test]
+fn failed_days_keep_their_place_in_json() {
+    let reports = [report(1, "10", "a\"b"), report(3, "30", "40")];
+    let failures = [Failure {
+        day: 2,
+        error: "couldn't parse input: line 1, column 1: \"x\"".to_string(),
+    }];
+    let json = output::render(&reports, &failures, Format::Json);
+
+    assert_eq!(
+        json,
+        concat!(
+            "{\"days\":[",
+            "{\"day\":1,\"answer_one\":\"10\",\"answer_two\":\"a\\\"b\",",
+            "\"answer_one_type\":\"u64\",\"answer_two_type\":\"String\",",
+            "\"parse_ns\":1000,\"part_one_ns\":2000,\"part_two_ns\":3000,",
+            "\"total_ns\":6000,\"error\":null},",
+            "{\"day\":2,\"answer_one\":null,\"answer_two\":null,",
+            "\"answer_one_type\":null,\"answer_two_type\":null,",
+            "\"parse_ns\":null,\"part_one_ns\":null,\"part_two_ns\":null,",
+            "\"total_ns\":null,",
+            "\"error\":\"couldn't parse input: line 1, column 1: \\\"x\\\"\"},",
+            "{\"day\":3,\"answer_one\":\"30\",\"answer_two\":\"40\",",
+            "\"answer_one_type\":\"u64\",\"answer_two_type\":\"String\",",
+            "\"parse_ns\":1000,\"part_one_ns\":2000,\"part_two_ns\":3000,",
+            "\"total_ns\":6000,\"error\":null}",
+            "],\"total\":{\"parse_ns\":2000,\"part_one_ns\":4000,",
+            "\"part_two_ns\":6000,\"total_ns\":12000}}\n"
+        )
+    );
+}
+
+#[test]
+fn failures_are_left_out_of_the_table_and_csv() {
+    let failures = [Failure {
+        day: 2,
+        error: "panicked".to_string(),
+    }];
+    assert_eq!(output::render(&[], &failures, Format::Table), "");
+
+    let csv = output::render(&[report(1, "10", "20")], &failures, Format::Csv);
+    assert_eq!(csv.lines().count(), 3);
+    assert!(!csv.contains("panicked"));
+}
@@ -0,0 +1,52 @@
+use aoc::{
+    generate::{self, Rng},
+    parallel, Solution,
+};
+
+/// How many random inputs each day is solved on.
+const SEEDS: u64 = 20;
+
+/// Solves a day's input with its `parallel` pieces using rayon and again one
+/// at a time, and checks both give the same answers.
+fn compare<S: Solution>(seed: u64, input: &str) {
+    let input = S::parse(input).unwrap_or_else(|err| panic!("day {}: {err}", S::DAY));
+    let solve = || {
+        (
+            S::part_one(&input).to_string(),
+            S::part_two(&input).to_string(),
+        )
+    };
+    assert_eq!(
+        solve(),
+        parallel::sequential(solve),
+        "day {} seed {seed}",
+        S::DAY
+    );
+}
+
+/// Without the `parallel` feature both ways are the same code, so this is
+/// only worth running with it:
+///
+/// ```sh
+/// cargo test -p runner --test properties --features parallel
+/// ```
+#[test]
+fn parallel_and_sequential_answers_match() {
+    for seed in 0..SEEDS {
+        let rng = &mut Rng::new(seed);
+        let (width, height) = (rng.range(3..=12) as usize, rng.range(3..=12) as usize);
+
+        compare::<day1::Day1>(seed, &generate::calibration(rng, 20, 12));
+        compare::<day2::Day2>(seed, &generate::cube_games(rng, 20, 5, 20));
+        compare::<day3::Day3>(seed, &generate::schematic(rng, width, height));
+        compare::<day4::Day4>(seed, &generate::scratchcards(rng, 12, 5, 8));
+        compare::<day5::Day5>(seed, &generate::almanac(rng, 3, 200, 6));
+        compare::<day6::Day6>(seed, &generate::races(rng, 3, 60));
+        compare::<day7::Day7>(seed, &generate::camel_hands(rng, 50, 1000));
+        compare::<day8::Day8>(seed, &generate::network(rng, 3, 4, 6));
+        compare::<day9::Day9>(seed, &generate::histories(rng, 5, 12, 8));
+        compare::<day10::Day10>(seed, &generate::pipe_maze(rng, width, height, 40));
+        compare::<day11::Day11>(seed, &generate::galaxy_image(rng, width, height, 15));
+        compare::<day12::Day12>(seed, &generate::spring_records(rng, 5, 14, 60));
+    }
+}
@@ -0,0 +1,181 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use runner::scaffold;
+
+const WORKSPACE: &str = r#"[workspace]
+resolver = "2"
+members = [
+    "aoc",
+    "day2/rust_sol",
+    "day4/rust_sol",
+    "runner",
+]
+
+[workspace.dependencies]
+aoc = { path = "aoc" }
+day2 = { path = "day2/rust_sol" }
+day4 = { path = "day4/rust_sol" }
+rayon = "1"
+"#;
+
+const RUNNER: &str = r#"[dependencies]
+aoc = { workspace = true, features = ["http"] }
+day2.workspace = true
+day4.workspace = true
+"#;
+
+const DAYS: &str = "pub const DAYS: &[Day] = &[
+    register::<day2::Day2>(),
+    register::<day4::Day4>(),
+];
+";
+
+/// Makes a workspace with days 2 and 4 in a new temporary directory.
+fn workspace(cargo_toml: &str) -> PathBuf {
+    static COUNT: AtomicUsize = AtomicUsize::new(0);
+    let root = env::temp_dir().join(format!(
+        "aoc-scaffold-{}-{}",
+        std::process::id(),
+        COUNT.fetch_add(1, Ordering::SeqCst)
+    ));
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(root.join("runner/src")).unwrap();
+    fs::write(root.join("Cargo.toml"), cargo_toml).unwrap();
+    fs::write(root.join("runner/Cargo.toml"), RUNNER).unwrap();
+    fs::write(root.join("runner/src/days.rs"), DAYS).unwrap();
+    root
+}
+
+/// Reads the lines of a workspace file that mention a day.
+fn day_lines(root: &Path, file: &str) -> Vec<String> {
+    fs::read_to_string(root.join(file))
+        .unwrap()
+        .lines()
+        .filter(|line| line.contains("day"))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Gets every file the scaffold edits.
+fn registrations(root: &Path) -> [String; 3] {
+    ["Cargo.toml", "runner/Cargo.toml", "runner/src/days.rs"]
+        .map(|file| fs::read_to_string(root.join(file)).unwrap())
+}
+
+#[test]
+fn days_are_registered_in_order() {
+    let root = workspace(WORKSPACE);
+    for day in [3, 1, 5] {
+        scaffold::new_day(&root, day).unwrap();
+    }
+
+    assert_eq!(
+        day_lines(&root, "Cargo.toml"),
+        [
+            "    \"day1/rust_sol\",",
+            "    \"day2/rust_sol\",",
+            "    \"day3/rust_sol\",",
+            "    \"day4/rust_sol\",",
+            "    \"day5/rust_sol\",",
+            "day1 = { path = \"day1/rust_sol\" }",
+            "day2 = { path = \"day2/rust_sol\" }",
+            "day3 = { path = \"day3/rust_sol\" }",
+            "day4 = { path = \"day4/rust_sol\" }",
+            "day5 = { path = \"day5/rust_sol\" }",
+        ]
+    );
+    assert_eq!(
+        day_lines(&root, "runner/Cargo.toml"),
+        [
+            "day1.workspace = true",
+            "day2.workspace = true",
+            "day3.workspace = true",
+            "day4.workspace = true",
+            "day5.workspace = true",
+        ]
+    );
+    assert_eq!(
+        day_lines(&root, "runner/src/days.rs"),
+        [
+            "    register::<day1::Day1>(),",
+            "    register::<day2::Day2>(),",
+            "    register::<day3::Day3>(),",
+            "    register::<day4::Day4>(),",
+            "    register::<day5::Day5>(),",
+        ]
+    );
+    // the rest of the files is left alone
+    let workspace = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+    assert!(workspace.contains("    \"day5/rust_sol\",\n    \"runner\",\n]"));
+    assert!(workspace.ends_with("day5 = { path = \"day5/rust_sol\" }\nrayon = \"1\"\n"));
+
+    let lib = fs::read_to_string(root.join("day3/rust_sol/src/lib.rs")).unwrap();
+    assert!(lib.contains("impl Solution for Day3 {"));
+    assert!(lib.contains("const DAY: u8 = 3;"));
+    assert!(root.join("day3/rust_sol/tests/example.rs").exists());
+    assert_eq!(
+        fs::read_to_string(root.join("day3/rust_sol/input.txt")).unwrap(),
+        ""
+    );
+
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn an_already_registered_day_is_an_error() {
+    let root = workspace(WORKSPACE);
+    let before = registrations(&root);
+
+    let err = scaffold::new_day(&root, 4).unwrap_err();
+    assert!(err.ends_with("day 4 is already listed"), "{err}");
+    assert_eq!(registrations(&root), before);
+    assert!(!root.join("day4").exists());
+
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn an_existing_directory_is_left_alone() {
+    let root = workspace(WORKSPACE);
+    let before = registrations(&root);
+    fs::create_dir_all(root.join("day6/rust_sol")).unwrap();
+    fs::write(root.join("day6/rust_sol/input.txt"), "mine").unwrap();
+
+    let err = scaffold::new_day(&root, 6).unwrap_err();
+    assert!(err.starts_with("day 6 already exists"), "{err}");
+    assert_eq!(registrations(&root), before);
+    assert_eq!(
+        fs::read_to_string(root.join("day6/rust_sol/input.txt")).unwrap(),
+        "mine"
+    );
+    assert!(!root.join("day6/rust_sol/src").exists());
+
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn a_workspace_without_members_registers_nothing() {
+    let root = workspace("[workspace]\nresolver = \"2\"\n");
+    let before = registrations(&root);
+
+    let err = scaffold::new_day(&root, 3).unwrap_err();
+    assert!(err.ends_with("couldn't find the list of days"), "{err}");
+    assert_eq!(registrations(&root), before);
+    assert!(!root.join("day3").exists());
+
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn only_days_1_to_25_can_be_made() {
+    let root = workspace(WORKSPACE);
+    for day in [0, 26] {
+        let err = scaffold::new_day(&root, day).unwrap_err();
+        assert_eq!(err, format!("there's no day {day}, days go from 1 to 25"));
+    }
+    fs::remove_dir_all(&root).unwrap();
+}